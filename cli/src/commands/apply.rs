@@ -1,23 +1,39 @@
 use std::{fs::remove_dir_all, path::PathBuf, sync::Arc};
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{ArgAction, Parser};
 use source_wand_concurrent_executor::{
     executor::execute_graph,
     transformation_node::TransformationNode
 };
 use source_wand_replication::{
     model::replication_plan::ReplicationPlan,
-    plan::planner::plan_replication
+    plan::{
+        lockfile::{
+            hash_replication_plan,
+            read_locked_replication_plan
+        },
+        planner::plan_replication
+    }
 };
 
 #[derive(Debug, Parser)]
-pub struct ApplyArgs;
+pub struct ApplyArgs {
+    #[arg(long, action = ArgAction::SetTrue, help = "Plan the replication again instead of using replication.lock.yaml")]
+    pub replan: bool,
+}
 
-pub fn replicate_apply_command(_args: &ApplyArgs) -> Result<()> {
-    let replication_plan: ReplicationPlan = plan_replication()?;
+pub fn replicate_apply_command(args: &ApplyArgs) -> Result<()> {
+    let replication_plan: ReplicationPlan = if args.replan {
+        plan_replication()?
+    }
+    else {
+        read_locked_replication_plan()?
+    };
 
-    let execution_graph: Vec<Arc<TransformationNode>> = replication_plan.to_execution_graph();
+    let execution_graph: Vec<Arc<TransformationNode>> = replication_plan.to_execution_graph(
+        &hash_replication_plan(&replication_plan)?
+    )?;
     execute_graph(execution_graph)?;
 
     remove_dir_all(PathBuf::from("./source-wand")).ok();
@@ -2,4 +2,7 @@ pub mod dependencies;
 pub mod init;
 pub mod plan;
 pub mod apply;
+pub mod status;
+pub mod upgrade;
+pub mod verify_build;
 pub mod replication;
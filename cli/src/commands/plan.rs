@@ -9,11 +9,14 @@ use source_wand_common::identity::{
     semantic_version::SemanticVersion
 };
 use source_wand_replication::{
-    model::{
-        package_origin::PackageOrigin,
-        replication_plan::ReplicationPlan
-    },
-    plan::planner::plan_replication
+    model::replication_plan::ReplicationPlan,
+    plan::{
+        lockfile::{
+            lock_replication_plan,
+            REPLICATION_LOCKFILE
+        },
+        planner::plan_replication
+    }
 };
 
 #[derive(Debug, Parser)]
@@ -41,6 +44,7 @@ pub fn replicate_plan_command(args: &PlanArgs) -> Result<()> {
     };
 
     let plan: ReplicationPlan = plan_replication()?;
+    lock_replication_plan(&plan)?;
 
     println!(
         "{} {} packages were identified as required to build the project",
@@ -49,24 +53,8 @@ pub fn replicate_plan_command(args: &PlanArgs) -> Result<()> {
     );
 
     for package in &plan.packages {
-        let (
-                name,
-                version,
-                source,
-        ) = match &package.origin {
-            PackageOrigin::Git(origin) => {
-                let name: SanitizedName = SanitizedName::new(&origin.git);
-                let version: SemanticVersion = SemanticVersion::new(&origin.reference);
-
-                (name, version, origin.git.clone())
-            },
-            PackageOrigin::GoCache(origin) => {
-                let name: SanitizedName = SanitizedName::new(&origin.name);
-                let version: SemanticVersion = SemanticVersion::new(&origin.version);
-
-                (name, version, origin.upstream.clone())
-            },
-        };
+        let name: SanitizedName = plan.sanitized_name(&package.origin);
+        let version: SemanticVersion = package.origin.semantic_version();
 
         println!(
             "\n{} package: {}",
@@ -83,16 +71,23 @@ pub fn replicate_plan_command(args: &PlanArgs) -> Result<()> {
         println!(
             "{} channel: {}",
             "[plan]".green(),
-            format!("{}-24.04/edge", version.retrocompatible).to_string().italic(),
+            plan.target(package).channel(&version).italic(),
         );
 
         println!(
             "{} source: {}",
             "[plan]".green(),
-            source.italic(),
+            package.origin.source().italic(),
         );
     }
 
+    println!(
+        "\n{} {} {}",
+        "[execute]".green(),
+        "locked replication plan to".blue(),
+        REPLICATION_LOCKFILE.to_string().italic(),
+    );
+
     if let Some(export_path) = export_path {
         let file: File = File::create(&export_path)?;
         let mut writer: BufWriter<File> = BufWriter::new(file);
@@ -100,32 +95,16 @@ pub fn replicate_plan_command(args: &PlanArgs) -> Result<()> {
         writeln!(writer, "package,version,track,source")?;
 
         for package in &plan.packages {
-            let (
-                name,
-                version,
-                source,
-            ) = match &package.origin {
-                PackageOrigin::Git(origin) => {
-                    let name: SanitizedName = SanitizedName::new(&origin.git);
-                    let version: SemanticVersion = SemanticVersion::new(&origin.reference);
-
-                    (name, version, origin.git.clone())
-                },
-                PackageOrigin::GoCache(origin) => {
-                    let name: SanitizedName = SanitizedName::new(&origin.name);
-                    let version: SemanticVersion = SemanticVersion::new(&origin.version);
-
-                    (name, version, origin.upstream.clone())
-                },
-            };
+            let name: SanitizedName = plan.sanitized_name(&package.origin);
+            let version: SemanticVersion = package.origin.semantic_version();
 
             writeln!(
                 writer,
                 "{},{},{},{}",
                 name.sanitized.clone(),
                 version.raw.clone(),
-                format!("{}-24.04", version.retrocompatible),
-                source,
+                plan.target(package).track(&version),
+                package.origin.source(),
             )?;
         }
 
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use clap::{ArgAction, Parser, ValueEnum};
+use colorize::AnsiColor;
+use source_wand_replication::{
+    model::replication_plan::ReplicationPlan,
+    plan::{
+        lockfile::read_locked_replication_plan,
+        planner::plan_replication
+    },
+    verify::status::{
+        replication_status,
+        PackageStatus,
+        ReplicaState
+    }
+};
+
+#[derive(Debug, Parser)]
+pub struct StatusArgs {
+    #[arg(long, action = ArgAction::SetTrue, help = "Plan the replication again instead of using replication.lock.yaml")]
+    pub replan: bool,
+
+    #[arg(long, short, value_enum, default_value = "table")]
+    pub format: StatusFormat,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum StatusFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
+pub fn replicate_status_command(args: &StatusArgs) -> Result<()> {
+    let replication_plan: ReplicationPlan = if args.replan {
+        plan_replication()?
+    }
+    else {
+        read_locked_replication_plan()?
+    };
+
+    let statuses: Vec<PackageStatus> = replication_status(&replication_plan)?;
+
+    match args.format {
+        StatusFormat::Table => print_table(&statuses),
+        StatusFormat::Json => println!("{}", serde_json::to_string_pretty(&statuses)?),
+        StatusFormat::Yaml => println!("{}", serde_yaml::to_string(&statuses)?),
+    }
+
+    Ok(())
+}
+
+fn print_table(statuses: &[PackageStatus]) {
+    let name_width: usize = statuses.iter()
+        .map(|status| status.name.len())
+        .chain(["PACKAGE".len()])
+        .max()
+        .unwrap_or_default();
+
+    let version_width: usize = statuses.iter()
+        .map(|status| status.version.len())
+        .chain(["VERSION".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:name_width$}  {:version_width$}  {:10}  DESTINATION",
+        "PACKAGE",
+        "VERSION",
+        "STATUS",
+    );
+
+    let indent: String = " ".repeat(name_width + version_width + 16);
+
+    for status in statuses {
+        let state: String = format!("{:10}", status.state);
+
+        println!(
+            "{:name_width$}  {:version_width$}  {}  {} ({})",
+            status.name,
+            status.version,
+            match status.state {
+                ReplicaState::UpToDate => state.green(),
+                ReplicaState::Missing => state.yellow(),
+                ReplicaState::Diverged => state.red(),
+                ReplicaState::Unverified => state.blue(),
+            },
+            status.destination,
+            status.reference,
+        );
+
+        for changed_file in &status.changed_files {
+            println!(
+                "{}{} {}",
+                indent,
+                "changed:".red(),
+                changed_file.clone().italic(),
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::{fs::remove_dir_all, path::PathBuf, sync::Arc};
+
+use anyhow::Result;
+use clap::{ArgAction, Parser};
+use colorize::AnsiColor;
+use source_wand_concurrent_executor::{
+    executor::execute_graph,
+    transformation_node::TransformationNode
+};
+use source_wand_replication::{
+    model::replication_plan::ReplicationPlan,
+    plan::{
+        lockfile::{
+            hash_replication_plan,
+            lock_replication_plan,
+            read_previous_replication_plan
+        },
+        planner::plan_replication,
+        upgrade::{
+            diff_replication_plans,
+            pending_replication_plan,
+            PlanDiff
+        }
+    }
+};
+
+#[derive(Debug, Parser)]
+pub struct UpgradeArgs {
+    #[arg(long, action = ArgAction::SetTrue, help = "Only show what changed since the previous plan, without replicating anything")]
+    pub dry_run: bool,
+}
+
+pub fn replicate_upgrade_command(args: &UpgradeArgs) -> Result<()> {
+    let previous_plan: ReplicationPlan = read_previous_replication_plan()?;
+    let plan: ReplicationPlan = plan_replication()?;
+
+    let diff: PlanDiff = diff_replication_plans(&previous_plan, &plan);
+
+    if diff.is_empty() {
+        println!(
+            "{} no module changed since the previous plan",
+            "[plan]".green(),
+        );
+    }
+
+    for (name, version) in &diff.added {
+        println!(
+            "{} added: {} {}",
+            "[plan]".green(),
+            name.clone().italic(),
+            version.clone().blue(),
+        );
+    }
+
+    for (name, version) in &diff.removed {
+        println!(
+            "{} removed: {} {}",
+            "[plan]".green(),
+            name.clone().italic(),
+            version.clone().blue(),
+        );
+    }
+
+    for bump in &diff.bumped {
+        println!(
+            "{} {}: {} {} -> {}",
+            "[plan]".green(),
+            if bump.is_downgrade { "downgraded" } else { "upgraded" },
+            bump.name.clone().italic(),
+            bump.from.clone().blue(),
+            bump.to.clone().blue(),
+        );
+    }
+
+    let pending_plan: ReplicationPlan = pending_replication_plan(&plan);
+
+    println!(
+        "{} {} of {} packages are not replicated yet",
+        "[plan]".green(),
+        format!("{}", pending_plan.packages.len()).blue(),
+        format!("{}", plan.packages.len()).blue(),
+    );
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    let execution_graph: Vec<Arc<TransformationNode>> = pending_plan.to_execution_graph(
+        &hash_replication_plan(&plan)?
+    )?;
+    execute_graph(execution_graph)?;
+
+    remove_dir_all(PathBuf::from("./source-wand")).ok();
+
+    lock_replication_plan(&plan)
+}
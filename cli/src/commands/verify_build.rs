@@ -0,0 +1,63 @@
+use anyhow::{bail, Result};
+use clap::{ArgAction, Parser};
+use colorize::AnsiColor;
+use source_wand_replication::{
+    model::replication_plan::ReplicationPlan,
+    plan::{
+        lockfile::read_locked_replication_plan,
+        planner::plan_replication
+    },
+    verify::verify_build::{
+        verify_build,
+        BuildVerification
+    }
+};
+
+#[derive(Debug, Parser)]
+pub struct VerifyBuildArgs {
+    #[arg(long, action = ArgAction::SetTrue, help = "Plan the replication again instead of using replication.lock.yaml")]
+    pub replan: bool,
+
+    #[arg(long, help = "The target to build for when the plan has several targets, i.e. ubuntu@22.04")]
+    pub target: Option<String>,
+}
+
+pub fn verify_build_command(args: &VerifyBuildArgs) -> Result<()> {
+    let replication_plan: ReplicationPlan = if args.replan {
+        plan_replication()?
+    }
+    else {
+        read_locked_replication_plan()?
+    };
+
+    let verification: BuildVerification = verify_build(&replication_plan, args.target.as_ref())?;
+
+    for module in &verification.missing_modules {
+        println!(
+            "{} missing module: {}",
+            "[verify]".red(),
+            module.clone().italic(),
+        );
+    }
+
+    if verification.is_successful() {
+        println!(
+            "{} the top-level project builds from its replicas only",
+            "[verify]".green(),
+        );
+
+        Ok(())
+    }
+    else if !verification.missing_modules.is_empty() {
+        bail!(
+            "{} modules are missing from the replicas",
+            verification.missing_modules.len(),
+        )
+    }
+    else {
+        bail!(
+            "the top-level project does not build from its replicas\n\n{}",
+            verification.build_error.unwrap_or_default(),
+        )
+    }
+}
@@ -24,6 +24,18 @@ use crate::commands::{
         replication_command,
         ReplicationArgs
     },
+    status::{
+        replicate_status_command,
+        StatusArgs
+    },
+    upgrade::{
+        replicate_upgrade_command,
+        UpgradeArgs
+    },
+    verify_build::{
+        verify_build_command,
+        VerifyBuildArgs
+    },
 };
 
 mod commands;
@@ -48,6 +60,15 @@ enum Command {
     #[command(about = "Apply the deep replication plan")]
     Apply(ApplyArgs),
 
+    #[command(about = "Replicate only the packages that changed since the previous plan")]
+    Upgrade(UpgradeArgs),
+
+    #[command(about = "Compare the replication plan against what already exists in the destinations")]
+    Status(StatusArgs),
+
+    #[command(about = "Verify the replicated packages are enough to build the project offline")]
+    VerifyBuild(VerifyBuildArgs),
+
     #[command(about = "[DEPRECATED] Replicate a project")]
     Replication(ReplicationArgs),
 }
@@ -58,6 +79,9 @@ fn main() -> Result<()> {
         Command::Init(args) => replicate_init_command(&args),
         Command::Plan(args) => replicate_plan_command(&args),
         Command::Apply(args) => replicate_apply_command(&args),
+        Command::Upgrade(args) => replicate_upgrade_command(&args),
+        Command::Status(args) => replicate_status_command(&args),
+        Command::VerifyBuild(args) => verify_build_command(&args),
         Command::Replication(args) => replication_command(&args),
     }
 }
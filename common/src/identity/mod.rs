@@ -1,2 +1,3 @@
+pub mod naming_scheme;
 pub mod sanitized_name;
 pub mod semantic_version;
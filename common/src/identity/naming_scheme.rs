@@ -0,0 +1,64 @@
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+
+use super::sanitized_name::SanitizedName;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamingScheme {
+    pub prefix: String,
+    pub max_length: usize,
+    pub hash_suffix: bool,
+    pub major_version_suffix: MajorVersionSuffix,
+}
+
+/// What to do with the major version suffix of a name, i.e. `/v2` in `github.com/foo/bar/v2`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MajorVersionSuffix {
+    /// The suffix is part of the name and is never dropped when the name is shortened.
+    #[default]
+    Keep,
+    /// The suffix is removed, so that every major version shares the same name.
+    Drop,
+}
+
+impl NamingScheme {
+    pub fn new(prefix: &str) -> Self {
+        NamingScheme {
+            prefix: prefix.to_string(),
+            max_length: 40,
+            hash_suffix: true,
+            major_version_suffix: MajorVersionSuffix::Keep,
+        }
+    }
+
+    pub fn sanitize(&self, original_name: &String) -> SanitizedName {
+        match self.major_version_suffix {
+            MajorVersionSuffix::Keep => SanitizedName::with_scheme(original_name, self),
+            MajorVersionSuffix::Drop => {
+                let major_version: Regex = Regex::new(r"[-/]v[0-9]+$").unwrap();
+                let name: String = major_version.replace(original_name, "").to_string();
+
+                SanitizedName::from_mapping(original_name, &SanitizedName::with_scheme(&name, self).sanitized)
+            },
+        }
+    }
+
+    /// Gives a name that collides with another one a suffix derived from its original name.
+    pub fn disambiguate(&self, name: &SanitizedName) -> SanitizedName {
+        let suffix: String = format!("-{:08x}", fnv1a(&name.original));
+        let length: usize = name.sanitized.len().min(self.max_length.saturating_sub(suffix.len()));
+
+        let mut base: String = name.sanitized.chars().take(length).collect();
+        while base.ends_with('-') {
+            base.pop();
+        }
+
+        SanitizedName::from_mapping(&name.original, &format!("{}{}", base, suffix))
+    }
+}
+
+/// A stable hash, unlike the standard library ones which may change between releases.
+fn fnv1a(value: &str) -> u32 {
+    value.bytes().fold(0x811c9dc5, |hash: u32, byte| (hash ^ byte as u32).wrapping_mul(0x01000193))
+}
@@ -1,3 +1,5 @@
+use super::naming_scheme::NamingScheme;
+
 #[readonly::make]
 pub struct SanitizedName {
     pub original: String,
@@ -6,15 +8,33 @@ pub struct SanitizedName {
 
 impl SanitizedName {
     pub fn new(original_name: &String) -> Self {
+        SanitizedName::with_prefix(original_name, "go")
+    }
+
+    pub fn with_prefix(original_name: &String, prefix: &str) -> Self {
+        NamingScheme::new(prefix).sanitize(original_name)
+    }
+
+    /// A name that was already sanitized, i.e. as recorded in a replication plan.
+    pub fn from_mapping(original_name: &String, sanitized_name: &String) -> Self {
+        SanitizedName {
+            original: original_name.clone(),
+            sanitized: sanitized_name.clone(),
+        }
+    }
+
+    pub fn with_scheme(original_name: &String, scheme: &NamingScheme) -> Self {
+        let prefix: &str = &scheme.prefix;
+        let max_length: usize = scheme.max_length;
         let mut sanitized_name: String;
 
-        if original_name.starts_with("go-") {
+        if prefix.is_empty() || original_name.starts_with(format!("{}-", prefix).as_str()) {
             sanitized_name = original_name.clone();
         } else {
-            sanitized_name = format!("go-{}", original_name);
+            sanitized_name = format!("{}-{}", prefix, original_name);
         }
 
-        if sanitized_name.len() > 40 {
+        if sanitized_name.len() > max_length {
             let parts: Vec<&str> = sanitized_name.split('-').collect();
 
             if parts.len() > 2 {
@@ -30,7 +50,7 @@ impl SanitizedName {
                     accumulative_name.push('-');
                     accumulative_name.push_str(parts[i]);
 
-                    if accumulative_name.len() < 40 {
+                    if accumulative_name.len() < max_length {
                         new_parts.push(parts[i]);
                     } else {
                         break;
@@ -41,13 +61,16 @@ impl SanitizedName {
                 sanitized_name = format!("{}-{}", domain_prefix, new_parts.join("-"));
             }
             else {
-                let max_length: usize = 40;
-                let prefix_length: usize = 3;
+                let prefix_length: usize = if prefix.is_empty() { 0 } else { prefix.len() + 1 };
 
-                let start_index: usize = sanitized_name.len() - (max_length - prefix_length);
+                let start_index: usize = sanitized_name.len() - max_length.saturating_sub(prefix_length);
                 let new_suffix: &str = &sanitized_name[start_index..];
 
-                sanitized_name = format!("go-{}", new_suffix);
+                sanitized_name = if prefix.is_empty() {
+                    new_suffix.to_string()
+                } else {
+                    format!("{}-{}", prefix, new_suffix)
+                };
             }
         }
 
@@ -61,4 +84,10 @@ impl SanitizedName {
         template
             .replace("$NAME", &self.sanitized)
     }
+
+    pub fn to_environment(&self) -> Vec<(String, String)> {
+        vec![
+            ("NAME".to_string(), self.sanitized.clone()),
+        ]
+    }
 }
@@ -1,6 +1,39 @@
-use regex::Regex;
+use std::{cmp::Ordering, iter::once};
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// How the versions of an ecosystem are written, compared and considered retrocompatible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionScheme {
+    /// Go modules, semantic versions with pseudo-versions and `+incompatible`, where `v0` gives no guarantee.
+    #[default]
+    Go,
+    /// Semantic versioning as understood by Cargo, where `0.x` versions are compatible within a minor version.
+    Semver,
+    /// PEP 440, used by Python distributions.
+    Pep440,
+    /// Maven versions, with qualifiers like `-SNAPSHOT`, `-rc1` or `.Final`.
+    Maven,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Numeric(u64),
+    Alphanumeric(String),
+}
+
+impl Identifier {
+    fn new(identifier: &str) -> Self {
+        identifier.parse::<u64>()
+            .map(Identifier::Numeric)
+            .unwrap_or(Identifier::Alphanumeric(identifier.to_lowercase()))
+    }
+}
 
 #[readonly::make]
+#[derive(Debug, Clone)]
 pub struct SemanticVersion {
     pub raw: String,
     pub major: String,
@@ -8,63 +41,73 @@ pub struct SemanticVersion {
     pub patch: String,
     pub suffix: String,
     pub retrocompatible: String,
+    pub scheme: VersionScheme,
+    pub is_pre_release: bool,
+    pub is_pseudo_version: bool,
+    epoch: u64,
+    release: Vec<u64>,
+    stage: Vec<Identifier>,
+}
+
+struct ParsedVersion {
+    epoch: u64,
+    release: Vec<u64>,
+    suffix: String,
+    stage: Vec<Identifier>,
+    is_pre_release: bool,
+    is_pseudo_version: bool,
+    retrocompatible: String,
 }
 
 impl SemanticVersion {
     pub fn new(original_version: &String) -> Self {
-        let mut major: String = String::new();
-        let mut minor: String = String::new();
-        let mut patch: String = String::new();
-        let mut suffix: String = String::new();
-
-        if original_version.starts_with('v') {
-            let parts: Vec<&str> = original_version.trim_start_matches('v').split('-').collect();
-            let semantic_version_parts: Vec<&str> = parts[0].split('.').collect();
-
-            if semantic_version_parts.len() > 0 {
-                major = semantic_version_parts[0].to_string();
-            }
-            if semantic_version_parts.len() > 1 {
-                minor = semantic_version_parts[1].to_string();
-            }
-            if semantic_version_parts.len() > 2 {
-                patch = semantic_version_parts[2].to_string();
-            }
-
-            if parts.len() > 1 {
-                suffix = format!("-{}", parts[1..].join("-"));
-            }
-        }
+        SemanticVersion::with_scheme(original_version, VersionScheme::Go)
+    }
 
-        let retrocompatible = if !suffix.is_empty() {
-            let re: Regex = Regex::new(r"(\d{14})-([a-f0-9]{12,40})$").unwrap();
-            if let Some(caps) = re.captures(&suffix) {
-                let datetime_str: &str = caps.get(1).unwrap().as_str();
-                let hash: &str = caps.get(2).unwrap().as_str();
-
-                let year: &str = &datetime_str[0..4];
-                let month: &str = &datetime_str[4..6];
-                let day: &str = &datetime_str[6..8];
-
-                format!("{}{}{}-{}", year, month, day, &hash[0..7])
-            } else {
-                format!("{}.{}.{}-{}", major, minor, patch, suffix)
-            }
-        } else {
-            if major == "0".to_string() {
-                format!("{}.{}.{}", major.clone(), minor, patch)
-            } else {
-                major.clone()
-            }
+    pub fn with_scheme(original_version: &String, scheme: VersionScheme) -> Self {
+        let parsed: Option<ParsedVersion> = match scheme {
+            VersionScheme::Go | VersionScheme::Semver => parse_semantic_version(original_version, scheme),
+            VersionScheme::Pep440 => parse_pep440_version(original_version),
+            VersionScheme::Maven => parse_maven_version(original_version),
         };
 
+        let Some(parsed) = parsed else {
+            return SemanticVersion {
+                raw: original_version.clone(),
+                major: String::new(),
+                minor: String::new(),
+                patch: String::new(),
+                suffix: String::new(),
+                retrocompatible: original_version.clone(),
+                scheme,
+                is_pre_release: false,
+                is_pseudo_version: false,
+                epoch: 0,
+                release: Vec::new(),
+                stage: vec![Identifier::new(original_version)],
+            };
+        };
+
+        let component = |index: usize| parsed.release.get(index).copied().unwrap_or(0).to_string();
+
+        let mut release: Vec<u64> = parsed.release.clone();
+        while release.last() == Some(&0) {
+            release.pop();
+        }
+
         SemanticVersion {
             raw: original_version.clone(),
-            major,
-            minor,
-            patch,
-            suffix,
-            retrocompatible,
+            major: component(0),
+            minor: component(1),
+            patch: component(2),
+            suffix: parsed.suffix,
+            retrocompatible: parsed.retrocompatible,
+            scheme,
+            is_pre_release: parsed.is_pre_release,
+            is_pseudo_version: parsed.is_pseudo_version,
+            epoch: parsed.epoch,
+            release,
+            stage: parsed.stage,
         }
     }
 
@@ -77,4 +120,394 @@ impl SemanticVersion {
             .replace("$VERSION_RETROCOMPATIBLE", &self.retrocompatible)
             .replace("$VERSION", &self.raw)
     }
+
+    pub fn to_environment(&self) -> Vec<(String, String)> {
+        vec![
+            ("VERSION".to_string(), self.raw.clone()),
+            ("VERSION_MAJOR".to_string(), self.major.clone()),
+            ("VERSION_MINOR".to_string(), self.minor.clone()),
+            ("VERSION_PATCH".to_string(), self.patch.clone()),
+            ("VERSION_SUFFIX".to_string(), self.suffix.clone()),
+            ("VERSION_RETROCOMPATIBLE".to_string(), self.retrocompatible.clone()),
+        ]
+    }
+}
+
+impl Ord for SemanticVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch.cmp(&other.epoch)
+            .then_with(|| self.release.cmp(&other.release))
+            .then_with(|| self.stage.cmp(&other.stage))
+            .then_with(|| self.raw.cmp(&other.raw))
+    }
+}
+
+impl PartialOrd for SemanticVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SemanticVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SemanticVersion {}
+
+/// Versions of the default scheme are written as plain strings, the others along with their scheme.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerializedVersion {
+    WithScheme { version: String, scheme: VersionScheme },
+    Raw(String),
+}
+
+impl Serialize for SemanticVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        match self.scheme {
+            VersionScheme::Go => SerializedVersion::Raw(self.raw.clone()),
+            scheme => SerializedVersion::WithScheme { version: self.raw.clone(), scheme },
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SemanticVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        match SerializedVersion::deserialize(deserializer)? {
+            SerializedVersion::WithScheme { version, scheme } => Ok(SemanticVersion::with_scheme(&version, scheme)),
+            SerializedVersion::Raw(version) => Ok(SemanticVersion::new(&version)),
+        }
+    }
+}
+
+fn parse_semantic_version(version: &String, scheme: VersionScheme) -> Option<ParsedVersion> {
+    let semantic_version: Regex = Regex::new(
+        r"^v?(\d+)(?:\.(\d+))?(?:\.(\d+))?(?:-([0-9A-Za-z.-]+))?(?:\+([0-9A-Za-z.-]+))?$"
+    ).unwrap();
+    let pseudo_version: Regex = Regex::new(r"(?:^|\.)(\d{14})-([a-f0-9]{12,40})$").unwrap();
+
+    let captures: Captures = semantic_version.captures(version)?;
+
+    let release: Vec<u64> = (1..=3)
+        .map(|group| captures.get(group).and_then(|number| number.as_str().parse().ok()).unwrap_or(0))
+        .collect();
+
+    let pre_release: Option<&str> = captures.get(4).map(|pre_release| pre_release.as_str());
+
+    let suffix_start: usize = captures.get(4)
+        .or(captures.get(5))
+        .map(|suffix| suffix.start() - 1)
+        .unwrap_or(version.len());
+
+    // Pseudo-versions are pre-releases, so they already sort before the release they precede.
+    let stage: Vec<Identifier> = match pre_release {
+        Some(pre_release) => once(Identifier::Numeric(0))
+            .chain(pre_release.split('.').map(Identifier::new))
+            .collect(),
+        None => vec![Identifier::Numeric(1)],
+    };
+
+    let pseudo_version_captures: Option<Captures> = pre_release
+        .filter(|_| scheme == VersionScheme::Go)
+        .and_then(|pre_release| pseudo_version.captures(pre_release));
+
+    let full_release: String = format!("{}.{}.{}", release[0], release[1], release[2]);
+
+    let retrocompatible: String = match (&pseudo_version_captures, pre_release) {
+        (Some(captures), _) => format!("{}-{}", &captures[1][0..8], &captures[2][0..7]),
+        (None, Some(pre_release)) => format!("{}-{}", full_release, pre_release),
+        (None, None) if release[0] > 0 => release[0].to_string(),
+        (None, None) if scheme == VersionScheme::Semver && release[1] > 0 => format!("0.{}", release[1]),
+        (None, None) => full_release,
+    };
+
+    Some(
+        ParsedVersion {
+            epoch: 0,
+            release,
+            suffix: version[suffix_start..].to_string(),
+            stage,
+            is_pre_release: pre_release.is_some(),
+            is_pseudo_version: pseudo_version_captures.is_some(),
+            retrocompatible,
+        }
+    )
+}
+
+fn parse_pep440_version(version: &String) -> Option<ParsedVersion> {
+    let pep440_version: Regex = Regex::new(
+        r"(?i)^v?(?:(\d+)!)?(\d+(?:\.\d+)*)(?:[-_.]?(alpha|a|beta|b|preview|pre|rc|c)[-_.]?(\d*))?(?:-(\d+)|[-_.]?(?:post|rev|r)[-_.]?(\d*))?(?:[-_.]?(dev)[-_.]?(\d*))?(?:\+([a-z0-9]+(?:[-_.][a-z0-9]+)*))?$"
+    ).unwrap();
+
+    let captures: Captures = pep440_version.captures(version)?;
+    let number = |group: usize| captures.get(group).and_then(|number| number.as_str().parse::<u64>().ok()).unwrap_or(0);
+
+    let epoch: u64 = number(1);
+    let release: Vec<u64> = captures[2]
+        .split('.')
+        .map(|number| number.parse().unwrap_or(0))
+        .collect();
+
+    let pre_release: Option<u64> = captures.get(3).map(|label| match label.as_str().to_lowercase().as_str() {
+        "alpha" | "a" => 1,
+        "beta" | "b" => 2,
+        _ => 3,
+    });
+    let post_release: Option<u64> = captures.get(5)
+        .or(captures.get(6))
+        .map(|_| number(5).max(number(6)));
+    let dev_release: Option<u64> = captures.get(7).map(|_| number(8));
+
+    // Development releases come before pre-releases, which come before the release and its post-releases.
+    let pre_release_rank: Vec<Identifier> = match (pre_release, post_release, dev_release) {
+        (Some(rank), _, _) => vec![Identifier::Numeric(rank), Identifier::Numeric(number(4))],
+        (None, None, Some(_)) => vec![Identifier::Numeric(0), Identifier::Numeric(0)],
+        (None, _, _) => vec![Identifier::Numeric(4), Identifier::Numeric(0)],
+    };
+
+    let stage: Vec<Identifier> = pre_release_rank
+        .into_iter()
+        .chain(once(Identifier::Numeric(post_release.map(|post| post + 1).unwrap_or(0))))
+        .chain(once(Identifier::Numeric(dev_release.unwrap_or(u64::MAX))))
+        .chain(
+            captures.get(9)
+                .map(|local| local.as_str().split(['-', '_', '.']).map(Identifier::new).collect::<Vec<Identifier>>())
+                .unwrap_or_default()
+        )
+        .collect();
+
+    let is_pre_release: bool = pre_release.is_some() || dev_release.is_some();
+    let epoch_prefix: String = if epoch > 0 { format!("{}!", epoch) } else { String::new() };
+
+    let retrocompatible: String = if is_pre_release {
+        version.to_lowercase()
+    }
+    else if release[0] > 0 {
+        format!("{}{}", epoch_prefix, release[0])
+    }
+    else {
+        format!("{}0.{}", epoch_prefix, release.get(1).copied().unwrap_or(0))
+    };
+
+    Some(
+        ParsedVersion {
+            epoch,
+            release,
+            suffix: version[captures.get(2).unwrap().end()..].to_string(),
+            stage,
+            is_pre_release,
+            is_pseudo_version: false,
+            retrocompatible,
+        }
+    )
+}
+
+fn parse_maven_version(version: &String) -> Option<ParsedVersion> {
+    let maven_version: Regex = Regex::new(r"^(\d+(?:\.\d+)*)(.*)$").unwrap();
+    let maven_qualifier: Regex = Regex::new(r"^([a-z]*)[-.]?(\d*)(.*)$").unwrap();
+
+    let captures: Captures = maven_version.captures(version)?;
+
+    let release: Vec<u64> = captures[1]
+        .split('.')
+        .map(|number| number.parse().unwrap_or(0))
+        .collect();
+
+    let qualifier: String = captures[2].trim_start_matches(['-', '.']).to_lowercase();
+    let qualifier_captures: Captures = maven_qualifier.captures(&qualifier)?;
+
+    let name: &str = &qualifier_captures[1];
+    let rank: u64 = match name {
+        "alpha" | "a" => 1,
+        "beta" | "b" => 2,
+        "milestone" | "m" => 3,
+        "rc" | "cr" => 4,
+        "snapshot" => 5,
+        "" | "ga" | "final" | "release" => 6,
+        "sp" => 7,
+        _ => 8,
+    };
+
+    let mut stage: Vec<Identifier> = vec![
+        Identifier::Numeric(rank),
+        Identifier::Alphanumeric(if rank == 8 { name.to_string() } else { String::new() }),
+        Identifier::Numeric(qualifier_captures[2].parse().unwrap_or(0)),
+    ];
+
+    let remainder: &str = qualifier_captures[3].trim_start_matches(['-', '.']);
+    if !remainder.is_empty() {
+        stage.push(Identifier::new(remainder));
+    }
+
+    let is_pre_release: bool = rank < 6 || remainder.ends_with("snapshot");
+
+    let retrocompatible: String = if is_pre_release || rank == 8 {
+        version.clone()
+    }
+    else if release[0] > 0 {
+        release[0].to_string()
+    }
+    else {
+        format!("0.{}", release.get(1).copied().unwrap_or(0))
+    };
+
+    Some(
+        ParsedVersion {
+            epoch: 0,
+            release,
+            suffix: captures[2].to_string(),
+            stage,
+            is_pre_release,
+            is_pseudo_version: false,
+            retrocompatible,
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ascending(scheme: VersionScheme, versions: Vec<&str>) {
+        for pair in versions.windows(2) {
+            let lower: SemanticVersion = SemanticVersion::with_scheme(&pair[0].to_string(), scheme);
+            let higher: SemanticVersion = SemanticVersion::with_scheme(&pair[1].to_string(), scheme);
+
+            assert_eq!(lower.cmp(&higher), Ordering::Less, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    fn assert_retrocompatible(scheme: VersionScheme, cases: Vec<(&str, &str)>) {
+        for (version, retrocompatible) in cases {
+            assert_eq!(SemanticVersion::with_scheme(&version.to_string(), scheme).retrocompatible, retrocompatible, "{}", version);
+        }
+    }
+
+    #[test]
+    fn orders_go_versions() {
+        assert_ascending(
+            VersionScheme::Go,
+            vec![
+                "v0.0.0-20240101000000-abcdef123456",
+                "v0.0.0-20250408123456-abcdef123456",
+                "v0.1.0",
+                "v1.2.3-0.20250408123456-abcdef123456",
+                "v1.2.3-alpha",
+                "v1.2.3-rc1",
+                "v1.2.3",
+                "v1.10.0",
+                "v2.0.0+incompatible",
+                "v2.1.0+incompatible",
+            ],
+        );
+    }
+
+    #[test]
+    fn orders_semantic_versions() {
+        assert_ascending(
+            VersionScheme::Semver,
+            vec!["0.9.9", "1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta", "1.0.0-beta.2", "1.0.0-beta.11", "1.0.0-rc.1", "1.0.0", "1.0.1"],
+        );
+    }
+
+    #[test]
+    fn orders_pep440_versions() {
+        assert_ascending(
+            VersionScheme::Pep440,
+            vec![
+                "1.0.dev0",
+                "1.0a1.dev1",
+                "1.0a1",
+                "1.0b2",
+                "1.0rc1",
+                "1.0",
+                "1.0+local.1",
+                "1.0.post1.dev0",
+                "1.0.post1",
+                "1.1",
+                "1!0.1",
+            ],
+        );
+    }
+
+    #[test]
+    fn orders_maven_versions() {
+        assert_ascending(
+            VersionScheme::Maven,
+            vec!["1.0-alpha1", "1.0-beta2", "1.0-M1", "1.0-rc1", "1.0-SNAPSHOT", "1.0", "1.0-sp1", "1.1", "1.10.0.Final"],
+        );
+    }
+
+    #[test]
+    fn considers_equivalent_spellings_equal() {
+        let cases: Vec<(VersionScheme, &str, &str)> = vec![
+            (VersionScheme::Go, "v1.2.0", "v1.2.0"),
+            (VersionScheme::Pep440, "1.0", "1.0.0"),
+            (VersionScheme::Pep440, "1.0RC1", "1.0rc1"),
+            (VersionScheme::Maven, "1.0.Final", "1.0-final"),
+        ];
+
+        for (scheme, left, right) in cases {
+            let left_version: SemanticVersion = SemanticVersion::with_scheme(&left.to_string(), scheme);
+            let right_version: SemanticVersion = SemanticVersion::with_scheme(&right.to_string(), scheme);
+
+            assert_eq!(left_version.release, right_version.release, "{} {}", left, right);
+            assert_eq!(left_version.stage, right_version.stage, "{} {}", left, right);
+        }
+    }
+
+    #[test]
+    fn gives_retrocompatible_go_versions() {
+        assert_retrocompatible(
+            VersionScheme::Go,
+            vec![
+                ("v3.4.2", "3"),
+                ("v0.5.0", "0.5.0"),
+                ("v2.0.0+incompatible", "2"),
+                ("v0.0.0-20250408123456-abcdef123456", "20250408-abcdef1"),
+                ("v1.2.4-0.20250408123456-abcdef123456", "20250408-abcdef1"),
+                // The baseline gave `1.2.3--rc1`, so branches named after pre-releases change.
+                ("v1.2.3-rc1", "1.2.3-rc1"),
+                ("not-a-version", "not-a-version"),
+            ],
+        );
+    }
+
+    #[test]
+    fn gives_retrocompatible_versions_of_other_schemes() {
+        assert_retrocompatible(VersionScheme::Semver, vec![("3.4.2", "3"), ("0.5.3", "0.5"), ("0.0.3", "0.0.3"), ("1.0.0-rc.1", "1.0.0-rc.1")]);
+        assert_retrocompatible(VersionScheme::Pep440, vec![("3.4.2", "3"), ("0.5.3.post1", "0.5"), ("2!1.0", "2!1"), ("2.0.0B1", "2.0.0b1")]);
+        assert_retrocompatible(VersionScheme::Maven, vec![("3.4.2.Final", "3"), ("0.5.3", "0.5"), ("1.0-SNAPSHOT", "1.0-SNAPSHOT")]);
+    }
+
+    #[test]
+    fn splits_versions_into_components() {
+        let version: SemanticVersion = SemanticVersion::new(&"v3.4.2-20250408".to_string());
+
+        assert_eq!((version.major.as_str(), version.minor.as_str(), version.patch.as_str()), ("3", "4", "2"));
+        assert_eq!(version.suffix, "-20250408");
+        assert!(version.is_pre_release);
+        assert!(!version.is_pseudo_version);
+    }
+
+    #[test]
+    fn serializes_go_versions_as_plain_strings() {
+        let go: SemanticVersion = SemanticVersion::new(&"v1.2.3".to_string());
+        let python: SemanticVersion = SemanticVersion::with_scheme(&"1.0rc1".to_string(), VersionScheme::Pep440);
+
+        assert_eq!(serde_yaml::to_string(&go).unwrap(), "v1.2.3\n");
+        assert_eq!(serde_yaml::to_string(&python).unwrap(), "version: 1.0rc1\nscheme: pep440\n");
+
+        for version in [go, python] {
+            let deserialized: SemanticVersion = serde_yaml::from_str(&serde_yaml::to_string(&version).unwrap()).unwrap();
+
+            assert_eq!(deserialized.raw, version.raw);
+            assert_eq!(deserialized.scheme, version.scheme);
+            assert_eq!(deserialized.retrocompatible, version.retrocompatible);
+        }
+    }
 }
@@ -100,7 +100,20 @@ fn handle_node_execution(
                     );
                 },
                 Err(e) => {
-                    *error.lock().unwrap() = Err(e);
+                    println!(
+                        "{:<120} context: {}",
+                        format!(
+                            "{} {} {}",
+                            "[error]".red(),
+                            node.transformation.get_name().blue(),
+                            e.to_string().lines().next().unwrap_or_default().to_string().italic(),
+                        ),
+                        node.workdesk,
+                    );
+
+                    *error.lock().unwrap() = Err(
+                        anyhow!("{} failed in {}: {}", node.transformation.get_name(), node.workdesk, e)
+                    );
                     return;
                 }
             }
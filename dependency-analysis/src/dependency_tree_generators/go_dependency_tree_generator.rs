@@ -106,6 +106,10 @@ fn parse_module(s: &str) -> (String, String) {
     }
 }
 
+pub fn resolve_repository_url(module_path: &str) -> String {
+    extract_repository_url(module_path, &mut HashMap::new())
+}
+
 fn extract_repository_url(module_path: &str, cache: &mut HashMap<String, String>) -> String {
     if let Some(url) = cache.get(module_path) {
         return url.clone();
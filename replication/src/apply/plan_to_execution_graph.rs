@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use std::{collections::HashMap, fs::canonicalize, path::PathBuf, sync::Arc};
+
+use anyhow::{bail, Result};
 
 use source_wand_common::identity::{
     sanitized_name::SanitizedName,
@@ -9,104 +11,508 @@ use source_wand_concurrent_executor::{
         ExecutionGraphBuilder,
         RcExecutionNodeBuilder
     },
+    transformation::Transformation,
     transformation_node::TransformationNode
 };
 
 use crate::{
     model::{
+        dependency::Dependency,
+        package::Package,
         package_destination::PackageDestination,
         package_origin::PackageOrigin,
-        replication_plan::ReplicationPlan
+        package_origin_go_cache::PackageOriginGoCache,
+        provenance::Provenance,
+        replication_config::{FetchStrategy, MetadataFormat},
+        replication_plan::ReplicationPlan,
+        target::Target
     },
     plan::{
+        lockfile::manifest_directory,
         transformations::{
+            cargo::fetch_source::CargoFetchSource,
+            maven::fetch_sources::MavenFetchSources,
+            python::fetch_sdist::PythonFetchSdist,
+            debian::initialize::DebianInitialize,
             git::{
+                git_bundle::GitBundle,
+                git_bundle_index::{BundleIndexEntry, GitBundleIndex},
                 git_init::GitInit,
-                git_push::GitPush
+                git_init_local::GitInitLocal,
+                git_push::GitPush,
+                git_tag::GitTag
+            },
+            golang::{
+                fetch_source::GolangFetchSource,
+                fetch_upstream::GolangFetchUpstream,
+                publish_proxy::GolangPublishProxy
             },
-            golang::fetch_source::GolangFetchSource,
+            hooks::run_hook::RunHook,
             initialize_project::InitializeProject,
+            patches::apply_patches::ApplyPatches,
             sourcecraft::initialize::SourcecraftInitialize
         }
     }
 };
 
 impl ReplicationPlan {
-    pub fn to_execution_graph(&self) -> Vec<Arc<TransformationNode>> {
+    /// Builds the transformations replicating every package of the plan. The replicated commits are traced back
+    /// to the plan hashed as `plan_hash`, which is the locked plan even when only some of its packages are replicated.
+    pub fn to_execution_graph(&self, plan_hash: &str) -> Result<Vec<Arc<TransformationNode>>> {
         let mut execution_graph_builder: ExecutionGraphBuilder = ExecutionGraphBuilder::new();
 
+        let hooks_workdesk: String = format!("{} (hooks)", self.project);
+
+        let before_all: Option<RcExecutionNodeBuilder> = run_hook(
+            "before_all",
+            self.hooks.as_ref().and_then(|hooks| hooks.before_all.clone()),
+            &self.project_environment(),
+        ).map(|hook| execution_graph_builder.create_node(hooks_workdesk.clone(), hook));
+
+        let mut last_nodes: Vec<RcExecutionNodeBuilder> = Vec::new();
+
+        let mut bundle_entries: HashMap<PathBuf, Vec<BundleIndexEntry>> = HashMap::new();
+        let mut bundle_nodes: HashMap<PathBuf, Vec<RcExecutionNodeBuilder>> = HashMap::new();
+
         for package in &self.packages {
-            if let PackageOrigin::GoCache(origin) = &package.origin {
-                let PackageDestination::Git(destination) = &package.destination;
-
-                let name: SanitizedName = SanitizedName::new(&origin.name);
-                let version: SemanticVersion = SemanticVersion::new(&origin.version);
-
-                let workdesk: String = format!(
-                    "{} ({}-24.04/edge)",
-                    name.sanitized,
-                    version.retrocompatible
-                );
-
-                let mut initialize_project: RcExecutionNodeBuilder = execution_graph_builder.create_node(
-                    workdesk.clone(),
-                    Arc::new(
-                        InitializeProject::new(
-                            GitInit::new(
-                                destination.git.clone(),
+            let (name, version, fetch_source) = match &package.origin {
+                PackageOrigin::GoCache(origin) => (
+                    self.sanitized_name(&package.origin),
+                    package.origin.semantic_version(),
+                    self.fetch_source(origin),
+                ),
+                PackageOrigin::CargoRegistry(origin) => (
+                    self.sanitized_name(&package.origin),
+                    package.origin.semantic_version(),
+                    Arc::new(CargoFetchSource::new(origin.path.clone(), origin.checksum.clone())) as Arc<dyn Transformation>,
+                ),
+                PackageOrigin::PythonSdist(origin) => (
+                    self.sanitized_name(&package.origin),
+                    package.origin.semantic_version(),
+                    Arc::new(PythonFetchSdist::new(origin.sdist.clone(), origin.sha256.clone())) as Arc<dyn Transformation>,
+                ),
+                PackageOrigin::Maven(origin) => (
+                    self.sanitized_name(&package.origin),
+                    package.origin.semantic_version(),
+                    Arc::new(MavenFetchSources::new(origin.sources_jar.clone(), origin.pom.clone())) as Arc<dyn Transformation>,
+                ),
+                _ => continue,
+            };
+
+            let target: Target = self.target(package);
+
+            let workdesk: String = format!(
+                "{} ({} {})",
+                name.sanitized,
+                target.base(),
+                target.channel(&version),
+            );
+
+            let mut environment: Vec<(String, String)> = vec![("PROJECT".to_string(), self.project.clone())];
+            environment.extend(name.to_environment());
+            environment.extend(version.to_environment());
+
+            let before_each: Option<Arc<dyn Transformation>> = run_hook(
+                "before_each",
+                self.hooks.as_ref().and_then(|hooks| hooks.before_each.clone()),
+                &environment,
+            );
+
+            let after_each: Option<Arc<dyn Transformation>> = run_hook(
+                "after_each",
+                self.hooks.as_ref().and_then(|hooks| hooks.after_each.clone()),
+                &environment,
+            );
+
+            let mut transformations: Vec<Arc<dyn Transformation>> = Vec::new();
+
+            match (&package.destination, &package.origin) {
+                (PackageDestination::Git(destination), _) => {
+                    transformations.extend(
+                        self.git_transformations(
+                            package,
+                            destination.git.clone(),
+                            destination.reference.clone(),
+                            fetch_source,
+                            before_each,
+                            plan_hash,
+                        )?
+                    );
+
+                    transformations.extend(self.git_tag(package, &version, &destination.tag));
+                },
+                (PackageDestination::Directory(destination), _) => {
+                    let repository: PathBuf = destination.repository(&name);
+
+                    transformations.push(
+                        Arc::new(
+                            GitInitLocal::new(
+                                repository.clone(),
                                 destination.reference.clone(),
-                                if let Some(config) = &self.config {
-                                    config.git_identity.clone()
-                                }
-                                else {
-                                    None
-                                },
-                            ),
-                            GolangFetchSource::new(origin.path.clone()),
+                                destination.bare,
+                            )
                         )
-                    )
-                );
+                    );
 
-                let mut push_code: RcExecutionNodeBuilder = execution_graph_builder.create_node(
-                    workdesk.clone(),
-                    Arc::new(
-                        GitPush::new(
+                    transformations.extend(
+                        self.git_transformations(
+                            package,
+                            repository.to_str().unwrap_or_default().to_string(),
                             destination.reference.clone(),
-                            "Replicate source code".to_string(),
-                        )
-                    )
-                );
-
-                let mut initialize_sourcecraft: RcExecutionNodeBuilder = execution_graph_builder.create_node(
-                    workdesk.clone(),
-                    Arc::new(
-                        SourcecraftInitialize::new(
-                            name.sanitized.clone(),
-                            format!("{}-24.04", version.retrocompatible.clone()),
-                            "ubuntu@24.04".to_string(),
-                            vec!["amd64".to_string()],
-                            package.dependencies.clone(),
-                            package.is_library,
+                            fetch_source,
+                            before_each,
+                            plan_hash,
+                        )?
+                    );
+
+                    transformations.extend(self.git_tag(package, &version, &destination.tag));
+                },
+                (PackageDestination::Bundle(destination), _) => {
+                    let repository: PathBuf = destination.repository(&name);
+
+                    transformations.push(
+                        Arc::new(
+                            GitInitLocal::new(
+                                repository.clone(),
+                                destination.reference.clone(),
+                                true,
+                            )
                         )
-                    )
-                );
+                    );
 
-                let push_sourcecraft_metadata: RcExecutionNodeBuilder = execution_graph_builder.create_node(
-                    workdesk.clone(),
-                    Arc::new(
-                        GitPush::new(
+                    transformations.extend(
+                        self.git_transformations(
+                            package,
+                            repository.to_str().unwrap_or_default().to_string(),
                             destination.reference.clone(),
-                            "Initialize sourcecraft".to_string(),
+                            fetch_source,
+                            before_each,
+                            plan_hash,
+                        )?
+                    );
+
+                    transformations.extend(self.git_tag(package, &version, &destination.tag));
+
+                    transformations.push(
+                        Arc::new(
+                            GitBundle::new(
+                                destination.bundle_file(&name),
+                                destination.reference.clone(),
+                                destination.tag.clone(),
+                            )
                         )
-                    )
-                );
+                    );
+
+                    bundle_entries.entry(destination.index_file()).or_default().push(
+                        BundleIndexEntry {
+                            name: name.sanitized.clone(),
+                            version: version.raw.clone(),
+                            reference: destination.reference.clone(),
+                            bundle: format!("{}/{}.bundle", name.sanitized, destination.reference),
+                            commit: None,
+                        }
+                    );
+                },
+                (PackageDestination::GoProxy(destination), PackageOrigin::GoCache(origin)) => {
+                    transformations.push(Arc::new(GolangFetchSource::new(origin.path.clone())));
 
-                push_code.borrow_mut().depends_on(&mut initialize_project);
-                initialize_sourcecraft.borrow_mut().depends_on(&mut push_code);
-                push_sourcecraft_metadata.borrow_mut().depends_on(&mut initialize_sourcecraft);
+                    transformations.extend(before_each);
+
+                    transformations.push(
+                        Arc::new(
+                            GolangPublishProxy::new(
+                                origin.upstream.clone(),
+                                origin.version.clone(),
+                                destination.goproxy.clone(),
+                            )
+                        )
+                    );
+                },
+                // Only Go modules can be published to a Go module proxy.
+                (PackageDestination::GoProxy(_), _) => continue,
             }
+
+            transformations.extend(after_each);
+
+            let mut previous: Option<RcExecutionNodeBuilder> = before_all.clone();
+
+            for transformation in transformations {
+                let node: RcExecutionNodeBuilder = execution_graph_builder.create_node(workdesk.clone(), transformation);
+
+                if let Some(previous) = &mut previous {
+                    node.borrow_mut().depends_on(previous);
+                }
+
+                previous = Some(node);
+            }
+
+            if let (PackageDestination::Bundle(destination), Some(previous)) = (&package.destination, &previous) {
+                bundle_nodes.entry(destination.index_file()).or_default().push(previous.clone());
+            }
+
+            last_nodes.extend(previous);
         }
 
-        execution_graph_builder.build()
+        for (index_file, entries) in bundle_entries {
+            let index: RcExecutionNodeBuilder = execution_graph_builder.create_node(
+                format!("{} (bundles)", self.project),
+                Arc::new(GitBundleIndex::new(index_file.clone(), entries)),
+            );
+
+            for bundle_node in bundle_nodes.get(&index_file).cloned().unwrap_or_default() {
+                index.borrow_mut().depends_on(&mut bundle_node.clone());
+            }
+
+            last_nodes.push(index);
+        }
+
+        let after_all: Option<RcExecutionNodeBuilder> = run_hook(
+            "after_all",
+            self.hooks.as_ref().and_then(|hooks| hooks.after_all.clone()),
+            &self.project_environment(),
+        ).map(|hook| execution_graph_builder.create_node(hooks_workdesk.clone(), hook));
+
+        if let Some(after_all) = after_all {
+            for last_node in before_all.iter().chain(last_nodes.iter()) {
+                after_all.borrow_mut().depends_on(&mut last_node.clone());
+            }
+        }
+
+        Ok(execution_graph_builder.build())
     }
+
+    fn fetch_source(&self, origin: &PackageOriginGoCache) -> Arc<dyn Transformation> {
+        let fetch_strategy: FetchStrategy = self.config
+            .as_ref()
+            .and_then(|config| config.fetch_strategy.clone())
+            .unwrap_or_default();
+
+        match fetch_strategy {
+            FetchStrategy::ModuleCache => {
+                Arc::new(GolangFetchSource::new(origin.path.clone()))
+            },
+            FetchStrategy::UpstreamHistory => {
+                Arc::new(
+                    GolangFetchUpstream::new(
+                        origin.upstream.clone(),
+                        origin.version.clone(),
+                        origin.commit.clone(),
+                    )
+                )
+            },
+        }
+    }
+
+    fn git_transformations(
+        &self,
+        package: &Package,
+        repository_url: String,
+        reference: String,
+        fetch_source: Arc<dyn Transformation>,
+        before_each: Option<Arc<dyn Transformation>>,
+        plan_hash: &str,
+    ) -> Result<Vec<Arc<dyn Transformation>>> {
+        let name: SanitizedName = self.sanitized_name(&package.origin);
+        let version: SemanticVersion = package.origin.semantic_version();
+        let target: Target = self.target(package);
+
+        let mut transformations: Vec<Arc<dyn Transformation>> = Vec::new();
+
+        let trailers: Vec<(String, String)> = Provenance::new(
+            package.origin.upstream_url(),
+            version.raw.clone(),
+            package.origin.hash(),
+            plan_hash.to_string(),
+        ).trailers();
+
+        transformations.push(
+            Arc::new(
+                InitializeProject::new(
+                    GitInit::new(
+                        repository_url,
+                        reference.clone(),
+                        if let Some(config) = &self.config {
+                            config.git_identity.clone()
+                        }
+                        else {
+                            None
+                        },
+                    ),
+                    fetch_source,
+                )
+            )
+        );
+
+        if let Some(patches) = self.patches_directory(&name)? {
+            transformations.push(
+                Arc::new(
+                    ApplyPatches::new(
+                        patches,
+                        "Replicate source code".to_string(),
+                        trailers.clone(),
+                    )
+                )
+            );
+        }
+
+        transformations.extend(before_each);
+
+        transformations.push(
+            Arc::new(
+                GitPush::new(
+                    reference.clone(),
+                    "Replicate source code".to_string(),
+                    trailers.clone(),
+                )
+            )
+        );
+
+        let (metadata_emitter, commit_text) = self.metadata_emitter(package, &name, &version, &target);
+
+        transformations.push(metadata_emitter);
+
+        transformations.push(
+            Arc::new(
+                GitPush::new(
+                    reference,
+                    commit_text,
+                    trailers,
+                )
+            )
+        );
+
+        Ok(transformations)
+    }
+
+    fn git_tag(&self, package: &Package, version: &SemanticVersion, tag: &Option<String>) -> Option<Arc<dyn Transformation>> {
+        let tag: &String = tag.as_ref()?;
+
+        Some(
+            Arc::new(
+                GitTag::new(
+                    tag.clone(),
+                    version.raw.clone(),
+                    package.origin.upstream_url(),
+                    package.origin.commit(),
+                )
+            )
+        )
+    }
+
+    fn metadata_emitter(
+        &self,
+        package: &Package,
+        name: &SanitizedName,
+        version: &SemanticVersion,
+        target: &Target,
+    ) -> (Arc<dyn Transformation>, String) {
+        let metadata_format: MetadataFormat = self.config
+            .as_ref()
+            .and_then(|config| config.metadata.clone())
+            .unwrap_or_default();
+
+        match (metadata_format, &package.origin) {
+            (MetadataFormat::Debian, PackageOrigin::GoCache(origin)) => (
+                Arc::new(
+                    DebianInitialize::new(
+                        origin.upstream.clone(),
+                        origin.version.clone(),
+                        self.maintainer(),
+                        self.dependencies_by_module(&package.dependencies),
+                        package.is_library,
+                    )
+                ),
+                "Initialize debian packaging".to_string(),
+            ),
+            // Debian packaging is only generated for Go modules for now.
+            _ => (
+                Arc::new(
+                    SourcecraftInitialize::new(
+                        name.sanitized.clone(),
+                        target.track(version),
+                        target,
+                        package,
+                    )
+                ),
+                "Initialize sourcecraft".to_string(),
+            ),
+        }
+    }
+
+    fn maintainer(&self) -> String {
+        match self.config.as_ref().and_then(|config| config.git_identity.as_ref()) {
+            Some(identity) => format!("{} <{}>", identity.username, identity.email),
+            None => "source-wand <source-wand@localhost>".to_string(),
+        }
+    }
+
+    /// Names the dependencies after their Go module path instead of their sanitized name.
+    fn dependencies_by_module(&self, dependencies: &[Dependency]) -> Vec<Dependency> {
+        let mut modules: HashMap<String, String> = self.packages
+            .iter()
+            .filter_map(|package| match &package.origin {
+                PackageOrigin::GoCache(origin) if !origin.upstream.is_empty() => {
+                    Some((self.sanitized_name(&package.origin).sanitized.clone(), origin.upstream.clone()))
+                },
+                _ => None,
+            })
+            .collect();
+
+        // Dependencies that are not replicated themselves (i.e. excluded ones) are only known by the names of the plan.
+        for (module, sanitized) in &self.names {
+            modules.entry(sanitized.clone()).or_insert(module.clone());
+        }
+
+        dependencies
+            .iter()
+            .map(|dependency| Dependency {
+                name: modules.get(&dependency.name).cloned().unwrap_or(dependency.name.clone()),
+                version: dependency.version.clone(),
+                platforms: dependency.platforms.clone(),
+            })
+            .collect()
+    }
+
+    /// The patches configured for a package, `<patches>/<name>`, relative to the replication manifest.
+    pub fn patches_directory(&self, name: &SanitizedName) -> Result<Option<PathBuf>> {
+        let Some(patches) = self.config.as_ref().and_then(|config| config.patches.as_ref()) else {
+            return Ok(None);
+        };
+
+        let patches: PathBuf = manifest_directory().join(patches);
+
+        if !patches.is_dir() {
+            bail!("The patches directory {} does not exist", patches.to_str().unwrap_or_default());
+        }
+
+        Ok(canonicalize(patches.join(&name.sanitized)).ok())
+    }
+
+    fn project_environment(&self) -> Vec<(String, String)> {
+        let mut environment: Vec<(String, String)> = vec![("PROJECT".to_string(), self.project.clone())];
+
+        let top_level: Option<&Package> = self.packages
+            .iter()
+            .find(|package| !package.is_library);
+
+        match top_level.map(|package| &package.origin) {
+            Some(PackageOrigin::Git(_)) | Some(PackageOrigin::Local(_)) | None => {},
+            Some(origin) => {
+                environment.extend(self.sanitized_name(origin).to_environment());
+                environment.extend(origin.semantic_version().to_environment());
+            },
+        }
+
+        environment
+    }
+}
+
+fn run_hook(
+    hook: &str,
+    command: Option<String>,
+    environment: &[(String, String)],
+) -> Option<Arc<dyn Transformation>> {
+    command.map(|command| {
+        Arc::new(RunHook::new(hook.to_string(), command, environment.to_vec())) as Arc<dyn Transformation>
+    })
 }
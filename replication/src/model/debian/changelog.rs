@@ -0,0 +1,41 @@
+use crate::model::debian::naming::debian_source_name;
+
+#[derive(Debug, Clone)]
+pub struct DebianChangelog {
+    pub module: String,
+    pub version: String,
+    pub maintainer: String,
+    pub date: String,
+}
+
+impl DebianChangelog {
+    pub fn new(
+        module: String,
+        version: String,
+        maintainer: String,
+        date: String,
+    ) -> Self {
+        DebianChangelog {
+            module,
+            version,
+            maintainer,
+            date,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        format!(
+            "{} ({}) UNRELEASED; urgency=medium\n\
+             \n  \
+             * Replicate {} version {}.\n\
+             \n \
+             -- {}  {}\n",
+            debian_source_name(&self.module),
+            self.version,
+            self.module,
+            self.version,
+            self.maintainer,
+            self.date,
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::model::dependency::Dependency;
+use crate::model::debian::naming::{debian_library_name, debian_program_name, debian_source_name};
+
+#[derive(Debug, Clone)]
+pub struct DebianControl {
+    pub module: String,
+    pub maintainer: String,
+    pub dependencies: Vec<Dependency>,
+    pub is_library: bool,
+}
+
+impl DebianControl {
+    pub fn new(
+        module: String,
+        maintainer: String,
+        dependencies: Vec<Dependency>,
+        is_library: bool,
+    ) -> Self {
+        DebianControl {
+            module,
+            maintainer,
+            dependencies,
+            is_library,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut build_depends: Vec<String> = vec![
+            "debhelper-compat (= 13)".to_string(),
+            "dh-sequence-golang".to_string(),
+            "golang-any".to_string(),
+        ];
+        build_depends.extend(
+            self.dependencies
+                .iter()
+                .map(|dependency| {
+                    match &dependency.platforms {
+                        Some(platforms) => format!("{} [{}]", debian_library_name(&dependency.name), platforms.join(" ")),
+                        None => debian_library_name(&dependency.name),
+                    }
+                })
+        );
+
+        let mut control: String = format!(
+            "Source: {}\n\
+             Section: golang\n\
+             Priority: optional\n\
+             Maintainer: {}\n\
+             Build-Depends: {}\n\
+             Standards-Version: 4.7.0\n\
+             Rules-Requires-Root: no\n\
+             XS-Go-Import-Path: {}\n\
+             \n",
+            debian_source_name(&self.module),
+            self.maintainer,
+            build_depends.join(",\n               "),
+            self.module,
+        );
+
+        if self.is_library {
+            let mut depends: Vec<String> = vec!["${misc:Depends}".to_string()];
+            depends.extend(
+                self.dependencies
+                    .iter()
+                    .map(|dependency| debian_library_name(&dependency.name))
+            );
+
+            control.push_str(
+                format!(
+                    "Package: {}\n\
+                     Architecture: all\n\
+                     Multi-Arch: foreign\n\
+                     Depends: {}\n\
+                     Description: {} (Go library)\n \
+                     Source code of the {} Go module, onboarded by source-wand.\n",
+                    debian_library_name(&self.module),
+                    depends.join(",\n         "),
+                    self.module,
+                    self.module,
+                ).as_str()
+            );
+        }
+        else {
+            control.push_str(
+                format!(
+                    "Package: {}\n\
+                     Architecture: any\n\
+                     Depends: ${{misc:Depends}},\n         ${{shlibs:Depends}}\n\
+                     Static-Built-Using: ${{misc:Static-Built-Using}}\n\
+                     Description: {} (Go program)\n \
+                     Built from the {} Go module, onboarded by source-wand.\n",
+                    debian_program_name(&self.module),
+                    self.module,
+                    self.module,
+                ).as_str()
+            );
+        }
+
+        control
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(name: &str, platforms: Option<Vec<&str>>) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: "1-24.04".to_string(),
+            platforms: platforms.map(|platforms| platforms.iter().map(|platform| platform.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn renders_libraries() {
+        let control: String = DebianControl::new(
+            "github.com/spf13/cobra".to_string(),
+            "source-wand <source-wand@localhost>".to_string(),
+            vec![
+                dependency("github.com/spf13/pflag", None),
+                dependency("golang.org/x/sys", Some(vec!["amd64", "arm64"])),
+            ],
+            true,
+        ).render();
+
+        assert!(control.starts_with("Source: golang-github-spf13-cobra\n"));
+        assert!(control.contains("XS-Go-Import-Path: github.com/spf13/cobra\n"));
+        assert!(control.contains("               golang-github-spf13-pflag-dev,\n"));
+        assert!(control.contains("               golang-golang-x-sys-dev [amd64 arm64]\n"));
+        assert!(control.contains("Package: golang-github-spf13-cobra-dev\nArchitecture: all\n"));
+        assert!(control.contains("Depends: ${misc:Depends},\n         golang-github-spf13-pflag-dev,\n         golang-golang-x-sys-dev\n"));
+    }
+
+    #[test]
+    fn renders_programs() {
+        let control: String = DebianControl::new(
+            "github.com/canonical/chisel".to_string(),
+            "source-wand <source-wand@localhost>".to_string(),
+            Vec::new(),
+            false,
+        ).render();
+
+        assert!(control.contains("Build-Depends: debhelper-compat (= 13),\n               dh-sequence-golang,\n               golang-any\n"));
+        assert!(control.contains("Package: chisel\nArchitecture: any\n"));
+        assert!(control.contains("Static-Built-Using: ${misc:Static-Built-Using}\n"));
+    }
+}
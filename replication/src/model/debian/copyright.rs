@@ -0,0 +1,118 @@
+#[derive(Debug, Clone)]
+pub struct DebianCopyright {
+    pub module: String,
+    pub license: String,
+    pub license_text: Option<String>,
+}
+
+impl DebianCopyright {
+    pub fn new(module: String, license_text: Option<String>) -> Self {
+        let license: String = license_text
+            .as_ref()
+            .map(|text| detect_license(text))
+            .unwrap_or("unknown".to_string());
+
+        DebianCopyright {
+            module,
+            license,
+            license_text,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut copyright: String = format!(
+            "Format: https://www.debian.org/doc/packaging-manuals/copyright-format/1.0/\n\
+             Upstream-Name: {}\n\
+             Source: https://{}\n\
+             \n\
+             Files: *\n\
+             Copyright: {} authors\n\
+             License: {}\n",
+            self.module,
+            self.module,
+            self.module,
+            self.license,
+        );
+
+        if let Some(license_text) = &self.license_text {
+            copyright.push_str(format!("\nLicense: {}\n", self.license).as_str());
+
+            for line in license_text.lines() {
+                if line.trim().is_empty() {
+                    copyright.push_str(" .\n");
+                }
+                else {
+                    copyright.push_str(format!(" {}\n", line.trim_end()).as_str());
+                }
+            }
+        }
+
+        copyright
+    }
+}
+
+/// Detects the SPDX identifier of a license from its text.
+pub fn detect_license(license_text: &str) -> String {
+    let text: String = license_text.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    let known_licenses: Vec<(&str, &str)> = vec![
+        ("Apache License Version 2.0", "Apache-2.0"),
+        ("Apache License, Version 2.0", "Apache-2.0"),
+        ("Mozilla Public License Version 2.0", "MPL-2.0"),
+        ("Mozilla Public License, version 2.0", "MPL-2.0"),
+        ("GNU LESSER GENERAL PUBLIC LICENSE Version 3", "LGPL-3.0"),
+        ("GNU GENERAL PUBLIC LICENSE Version 3", "GPL-3.0"),
+        ("GNU GENERAL PUBLIC LICENSE Version 2", "GPL-2.0"),
+        ("GNU AFFERO GENERAL PUBLIC LICENSE Version 3", "AGPL-3.0"),
+        ("Permission to use, copy, modify, and/or distribute this software for any purpose", "ISC"),
+        ("Permission to use, copy, modify, and distribute this software for any purpose", "ISC"),
+        ("Permission is hereby granted, free of charge", "Expat"),
+        ("This is free and unencumbered software released into the public domain", "Unlicense"),
+    ];
+
+    for (marker, license) in known_licenses {
+        if text.contains(marker) {
+            return license.to_string();
+        }
+    }
+
+    if text.contains("Redistribution and use in source and binary forms") {
+        if text.contains("Neither the name") || text.contains("names of its contributors") {
+            return "BSD-3-clause".to_string();
+        }
+        return "BSD-2-clause".to_string();
+    }
+
+    "unknown".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_licenses() {
+        let cases: Vec<(&str, &str)> = vec![
+            ("Apache License\n  Version 2.0, January 2004", "Apache-2.0"),
+            ("MIT License\n\nPermission is hereby granted, free of charge, to any person", "Expat"),
+            ("Redistribution and use in source and binary forms, with or without\nmodification. Neither the name of Google", "BSD-3-clause"),
+            ("Redistribution and use in source and binary forms, with or without modification", "BSD-2-clause"),
+            ("All rights reserved.", "unknown"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(detect_license(text), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn renders_the_license_text() {
+        let copyright: String = DebianCopyright::new(
+            "github.com/spf13/cobra".to_string(),
+            Some("Apache License\nVersion 2.0\n\nTERMS AND CONDITIONS\n".to_string()),
+        ).render();
+
+        assert!(copyright.contains("Upstream-Name: github.com/spf13/cobra\nSource: https://github.com/spf13/cobra\n"));
+        assert!(copyright.contains("License: Apache-2.0\n Apache License\n Version 2.0\n .\n TERMS AND CONDITIONS\n"));
+    }
+}
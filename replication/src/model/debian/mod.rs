@@ -0,0 +1,5 @@
+pub mod naming;
+
+pub mod control;
+pub mod changelog;
+pub mod copyright;
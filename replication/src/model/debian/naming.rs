@@ -0,0 +1,112 @@
+use regex::Regex;
+use source_wand_common::identity::semantic_version::SemanticVersion;
+
+/// Maps a Go module path to a Debian source package name, following the
+/// conventions of the Debian Go team (i.e. `github.com/foo/bar` -> `golang-github-foo-bar`).
+pub fn debian_source_name(module: &str) -> String {
+    let mut segments: Vec<String> = module
+        .trim_end_matches('/')
+        .split('/')
+        .map(|segment| segment.to_string())
+        .collect();
+
+    if let Some(host) = segments.first_mut() {
+        let host_segments: Vec<&str> = host.split('.').collect();
+        if host_segments.len() > 1 {
+            *host = host_segments[..host_segments.len() - 1].join(".");
+        }
+    }
+
+    let name: String = format!("golang-{}", segments.join("-"))
+        .to_lowercase()
+        .replace(['/', '.', '_'], "-");
+
+    name.chars()
+        .filter(|character| character.is_ascii_alphanumeric() || *character == '-' || *character == '+')
+        .collect()
+}
+
+/// Name of the binary package shipping the source code of a Go library.
+pub fn debian_library_name(module: &str) -> String {
+    format!("{}-dev", debian_source_name(module))
+}
+
+/// Name of the binary package shipping a Go program, named after the last element of its module path.
+pub fn debian_program_name(module: &str) -> String {
+    let last: &str = module
+        .trim_end_matches('/')
+        .rsplit('/')
+        .find(|segment| !Regex::new(r"^v\d+$").unwrap().is_match(segment))
+        .unwrap_or(module);
+
+    last.to_lowercase().replace(['_', '.'], "-")
+}
+
+/// Maps a Go module version to a Debian version: pre-releases sort before releases (`~`),
+/// pseudo-versions become `~git<date>.<commit>` snapshots and `+incompatible` is dropped.
+pub fn debian_version(version: &SemanticVersion) -> String {
+    if version.major.is_empty() {
+        return format!("{}-1", version.raw.trim_start_matches('v'));
+    }
+
+    let patch: String = version.patch.replace("+incompatible", "");
+    let suffix: String = version.suffix.replace("+incompatible", "");
+
+    let mut upstream_version: String = format!("{}.{}.{}", version.major, version.minor, patch);
+
+    let pseudo_version: Regex = Regex::new(r"(\d{14})-([a-f0-9]{12,40})$").unwrap();
+
+    if let Some(caps) = pseudo_version.captures(&suffix) {
+        let datetime: &str = caps.get(1).unwrap().as_str();
+        let hash: &str = caps.get(2).unwrap().as_str();
+
+        upstream_version = format!("{}~git{}.{}", upstream_version, &datetime[0..8], &hash[0..7]);
+    }
+    else if !suffix.is_empty() {
+        upstream_version = format!("{}~{}", upstream_version, suffix.trim_start_matches('-').replace('-', "."));
+    }
+
+    format!("{}-1", upstream_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_source_packages_after_module_paths() {
+        let cases: Vec<(&str, &str)> = vec![
+            ("github.com/spf13/cobra", "golang-github-spf13-cobra"),
+            ("golang.org/x/sys", "golang-golang-x-sys"),
+            ("gopkg.in/yaml.v3", "golang-gopkg-yaml-v3"),
+            ("github.com/BurntSushi/toml", "golang-github-burntsushi-toml"),
+            ("github.com/go-logr/logr/", "golang-github-go-logr-logr"),
+        ];
+
+        for (module, expected) in cases {
+            assert_eq!(debian_source_name(module), expected, "{}", module);
+        }
+    }
+
+    #[test]
+    fn names_binary_packages() {
+        assert_eq!(debian_library_name("github.com/spf13/cobra"), "golang-github-spf13-cobra-dev");
+        assert_eq!(debian_program_name("github.com/canonical/chisel"), "chisel");
+        assert_eq!(debian_program_name("github.com/foo/my_tool/v2"), "my-tool");
+    }
+
+    #[test]
+    fn maps_go_versions_to_debian_versions() {
+        let cases: Vec<(&str, &str)> = vec![
+            ("v1.9.1", "1.9.1-1"),
+            ("v2.0.0-rc.1", "2.0.0~rc.1-1"),
+            ("v0.0.0-20250408123456-abcdef123456", "0.0.0~git20250408.abcdef1-1"),
+            ("v1.2.3-0.20250408123456-abcdef123456", "1.2.3~git20250408.abcdef1-1"),
+            ("v4.1.0+incompatible", "4.1.0-1"),
+        ];
+
+        for (version, expected) in cases {
+            assert_eq!(debian_version(&SemanticVersion::new(&version.to_string())), expected, "{}", version);
+        }
+    }
+}
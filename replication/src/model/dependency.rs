@@ -4,4 +4,5 @@ use serde::{Serialize, Deserialize};
 pub struct Dependency {
     pub name: String,
     pub version: String,
+    pub platforms: Option<Vec<String>>,
 }
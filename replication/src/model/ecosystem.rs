@@ -0,0 +1,51 @@
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::{
+    naming_scheme::NamingScheme,
+    semantic_version::VersionScheme
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ecosystem {
+    #[default]
+    Go,
+    Rust,
+    Python,
+    Java,
+}
+
+impl Ecosystem {
+    /// Prefix of the sanitized names of the packages of this ecosystem.
+    pub fn prefix(&self) -> &str {
+        match self {
+            Ecosystem::Go => "go",
+            Ecosystem::Rust => "rust",
+            Ecosystem::Python => "python",
+            Ecosystem::Java => "java",
+        }
+    }
+
+    pub fn version_scheme(&self) -> VersionScheme {
+        match self {
+            Ecosystem::Go => VersionScheme::Go,
+            Ecosystem::Rust => VersionScheme::Semver,
+            Ecosystem::Python => VersionScheme::Pep440,
+            Ecosystem::Java => VersionScheme::Maven,
+        }
+    }
+
+    pub fn naming_scheme(&self) -> NamingScheme {
+        NamingScheme::new(self.prefix())
+    }
+
+    /// Turns the name of a package into a name made of dash separated words.
+    pub fn normalize(&self, name: &str) -> String {
+        match self {
+            Ecosystem::Go => name.replace("/", "-").replace(".", "-"),
+            Ecosystem::Rust => name.replace('_', "-"),
+            Ecosystem::Python => Regex::new(r"[-_.]+").unwrap().replace_all(name, "-").to_lowercase(),
+            Ecosystem::Java => name.replace('.', "-"),
+        }
+    }
+}
@@ -1,17 +1,31 @@
 pub mod replication_config;
 pub mod replication_manifest;
 pub mod replication_plan;
+pub mod replication_lock;
+pub mod target;
+pub mod ecosystem;
+pub mod naming;
 
 pub mod hooks;
+pub mod package_override;
 
 pub mod package;
 pub mod dependency;
+pub mod provenance;
 
 pub mod package_origin;
 pub mod package_origin_git;
 pub mod package_origin_go_cache;
+pub mod package_origin_cargo_registry;
+pub mod package_origin_python_sdist;
+pub mod package_origin_maven;
+pub mod package_origin_local;
 
 pub mod package_destination;
 pub mod package_destination_git;
+pub mod package_destination_go_proxy;
+pub mod package_destination_directory;
+pub mod package_destination_bundle;
 
 pub mod sourcecraft;
+pub mod debian;
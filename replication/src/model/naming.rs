@@ -0,0 +1,48 @@
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::naming_scheme::{MajorVersionSuffix, NamingScheme};
+
+use crate::model::ecosystem::Ecosystem;
+
+/// The naming schemes of the replicated packages, per ecosystem.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Naming {
+    pub go: Option<NamingOverride>,
+    pub rust: Option<NamingOverride>,
+    pub python: Option<NamingOverride>,
+    pub java: Option<NamingOverride>,
+}
+
+impl Naming {
+    pub fn scheme(&self, ecosystem: Ecosystem) -> NamingScheme {
+        let naming_override: Option<&NamingOverride> = match ecosystem {
+            Ecosystem::Go => self.go.as_ref(),
+            Ecosystem::Rust => self.rust.as_ref(),
+            Ecosystem::Python => self.python.as_ref(),
+            Ecosystem::Java => self.java.as_ref(),
+        };
+
+        match naming_override {
+            Some(naming_override) => naming_override.apply(ecosystem.naming_scheme()),
+            None => ecosystem.naming_scheme(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamingOverride {
+    pub prefix: Option<String>,
+    pub max_length: Option<usize>,
+    pub hash_suffix: Option<bool>,
+    pub major_version_suffix: Option<MajorVersionSuffix>,
+}
+
+impl NamingOverride {
+    pub fn apply(&self, scheme: NamingScheme) -> NamingScheme {
+        NamingScheme {
+            prefix: self.prefix.clone().unwrap_or(scheme.prefix),
+            max_length: self.max_length.unwrap_or(scheme.max_length),
+            hash_suffix: self.hash_suffix.unwrap_or(scheme.hash_suffix),
+            major_version_suffix: self.major_version_suffix.clone().unwrap_or(scheme.major_version_suffix),
+        }
+    }
+}
@@ -1,6 +1,13 @@
+use std::collections::HashMap;
+
 use serde::{Serialize, Deserialize};
 
-use crate::model::{dependency::Dependency, package_destination::PackageDestination, package_origin::PackageOrigin};
+use crate::model::{
+    dependency::Dependency,
+    package_destination::PackageDestination,
+    package_origin::PackageOrigin,
+    target::Target
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Package {
@@ -8,6 +15,15 @@ pub struct Package {
     pub destination: PackageDestination,
     pub dependencies: Vec<Dependency>,
     pub is_library: bool,
+
+    #[serde(default)]
+    pub build_snaps: Vec<String>,
+    #[serde(default)]
+    pub build_environment: Vec<HashMap<String, String>>,
+
+    /// The target the package was planned for, when the plan has several targets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Target>,
 }
 
 impl Package {
@@ -16,7 +32,9 @@ impl Package {
         destination: PackageDestination,
         dependencies: Vec<Dependency>,
         is_library: bool,
+        build_snaps: Vec<String>,
+        build_environment: Vec<HashMap<String, String>>,
     ) -> Self {
-        Package { origin, destination, dependencies, is_library }
+        Package { origin, destination, dependencies, is_library, build_snaps, build_environment, target: None }
     }
 }
@@ -1,9 +1,64 @@
 use serde::{Serialize, Deserialize};
+use source_wand_common::identity::{
+    sanitized_name::SanitizedName,
+    semantic_version::SemanticVersion
+};
 
-use crate::model::package_destination_git::PackageDestinationGit;
+use crate::model::{
+    package_destination_bundle::PackageDestinationBundle,
+    package_destination_directory::PackageDestinationDirectory,
+    package_destination_git::PackageDestinationGit,
+    package_destination_go_proxy::PackageDestinationGoProxy,
+    target::Target
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum PackageDestination {
     Git(PackageDestinationGit),
+    GoProxy(PackageDestinationGoProxy),
+    Directory(PackageDestinationDirectory),
+    Bundle(PackageDestinationBundle),
+}
+
+impl PackageDestination {
+    pub fn apply(&self, name: &SanitizedName, version: &SemanticVersion, target: &Target) -> PackageDestination {
+        let apply = |template: &String| target.apply(&version.apply(&name.apply(template)));
+
+        match self {
+            PackageDestination::Git(destination) => {
+                PackageDestinationGit::new(
+                    apply(&destination.git),
+                    apply(&destination.reference),
+                    destination.tag.as_ref().map(apply),
+                )
+            },
+            PackageDestination::GoProxy(destination) => {
+                PackageDestination::GoProxy(
+                    PackageDestinationGoProxy::new(
+                        apply(&destination.goproxy),
+                    )
+                )
+            },
+            PackageDestination::Directory(destination) => {
+                PackageDestination::Directory(
+                    PackageDestinationDirectory::new(
+                        apply(&destination.directory),
+                        apply(&destination.reference),
+                        destination.bare,
+                        destination.tag.as_ref().map(apply),
+                    )
+                )
+            },
+            PackageDestination::Bundle(destination) => {
+                PackageDestination::Bundle(
+                    PackageDestinationBundle::new(
+                        apply(&destination.bundle),
+                        apply(&destination.reference),
+                        destination.tag.as_ref().map(apply),
+                    )
+                )
+            },
+        }
+    }
 }
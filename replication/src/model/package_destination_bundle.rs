@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::sanitized_name::SanitizedName;
+
+use crate::model::package_destination_directory::absolute;
+
+pub const BUNDLE_INDEX: &str = "index.yaml";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageDestinationBundle {
+    pub bundle: String,
+    pub reference: String,
+    pub tag: Option<String>,
+}
+
+impl PackageDestinationBundle {
+    pub fn new(bundle: String, reference: String, tag: Option<String>) -> Self {
+        PackageDestinationBundle { bundle, reference, tag }
+    }
+
+    /// The bare repository the package is pushed to before being bundled.
+    pub fn repository(&self, name: &SanitizedName) -> PathBuf {
+        absolute(&self.bundle).join(".repositories").join(&name.sanitized)
+    }
+
+    /// The bundle of a package, `<bundle>/<name>/<reference>.bundle`.
+    pub fn bundle_file(&self, name: &SanitizedName) -> PathBuf {
+        absolute(&self.bundle).join(&name.sanitized).join(format!("{}.bundle", self.reference))
+    }
+
+    pub fn index_file(&self) -> PathBuf {
+        absolute(&self.bundle).join(BUNDLE_INDEX)
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::sanitized_name::SanitizedName;
+
+use crate::plan::lockfile::manifest_directory;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageDestinationDirectory {
+    pub directory: String,
+    pub reference: String,
+    #[serde(default)]
+    pub bare: bool,
+    pub tag: Option<String>,
+}
+
+impl PackageDestinationDirectory {
+    pub fn new(directory: String, reference: String, bare: bool, tag: Option<String>) -> Self {
+        PackageDestinationDirectory { directory, reference, bare, tag }
+    }
+
+    /// The repository of a package, `<directory>/<name>/<reference>`.
+    pub fn repository(&self, name: &SanitizedName) -> PathBuf {
+        absolute(&self.directory).join(&name.sanitized).join(&self.reference)
+    }
+}
+
+/// Resolves a path of the manifest, relative paths being relative to the directory of the manifest.
+pub fn absolute(path: &str) -> PathBuf {
+    manifest_directory().join(path)
+}
@@ -6,10 +6,11 @@ use crate::model::package_destination::PackageDestination;
 pub struct PackageDestinationGit {
     pub git: String,
     pub reference: String,
+    pub tag: Option<String>,
 }
 
 impl PackageDestinationGit {
-    pub fn new(git: String, reference: String) -> PackageDestination {
-        PackageDestination::Git(PackageDestinationGit { git, reference })
+    pub fn new(git: String, reference: String, tag: Option<String>) -> PackageDestination {
+        PackageDestination::Git(PackageDestinationGit { git, reference, tag })
     }
 }
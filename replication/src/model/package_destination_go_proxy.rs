@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageDestinationGoProxy {
+    pub goproxy: String,
+}
+
+impl PackageDestinationGoProxy {
+    pub fn new(goproxy: String) -> Self {
+        PackageDestinationGoProxy { goproxy }
+    }
+}
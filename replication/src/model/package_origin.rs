@@ -1,10 +1,109 @@
 use serde::{Serialize, Deserialize};
+use source_wand_common::identity::semantic_version::SemanticVersion;
 
-use crate::model::{package_origin_git::PackageOriginGit, package_origin_go_cache::PackageOriginGoCache};
+use crate::model::{
+    ecosystem::Ecosystem,
+    package_origin_cargo_registry::PackageOriginCargoRegistry,
+    package_origin_git::PackageOriginGit,
+    package_origin_go_cache::PackageOriginGoCache,
+    package_origin_local::PackageOriginLocal,
+    package_origin_maven::PackageOriginMaven,
+    package_origin_python_sdist::PackageOriginPythonSdist
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum PackageOrigin {
     Git(PackageOriginGit),
+    CargoRegistry(PackageOriginCargoRegistry),
+    PythonSdist(PackageOriginPythonSdist),
+    Maven(PackageOriginMaven),
     GoCache(PackageOriginGoCache),
+    Local(PackageOriginLocal),
+}
+
+impl PackageOrigin {
+    /// Where the package comes from, as listed by `source-wand plan`.
+    pub fn source(&self) -> String {
+        match self {
+            PackageOrigin::Git(origin) => origin.git.clone(),
+            PackageOrigin::GoCache(origin) => origin.upstream.clone(),
+            PackageOrigin::CargoRegistry(origin) => origin.name.clone(),
+            PackageOrigin::PythonSdist(origin) => origin.name.clone(),
+            PackageOrigin::Maven(origin) => origin.scm.clone().unwrap_or(origin.name()),
+            PackageOrigin::Local(origin) => origin.path.clone(),
+        }
+    }
+
+    /// The name of the package in its ecosystem (module path, crate, distribution, `group:artifact`).
+    pub fn name(&self) -> String {
+        match self {
+            PackageOrigin::Git(origin) => origin.git.clone(),
+            PackageOrigin::GoCache(origin) if !origin.upstream.is_empty() => origin.upstream.clone(),
+            PackageOrigin::GoCache(origin) => origin.name.clone(),
+            PackageOrigin::CargoRegistry(origin) => origin.name.clone(),
+            PackageOrigin::PythonSdist(origin) => origin.name.clone(),
+            PackageOrigin::Maven(origin) => origin.name(),
+            PackageOrigin::Local(origin) => origin.path.clone(),
+        }
+    }
+
+    pub fn version(&self) -> String {
+        match self {
+            PackageOrigin::Git(origin) => origin.reference.clone(),
+            PackageOrigin::GoCache(origin) => origin.version.clone(),
+            PackageOrigin::CargoRegistry(origin) => origin.version.clone(),
+            PackageOrigin::PythonSdist(origin) => origin.version.clone(),
+            PackageOrigin::Maven(origin) => origin.version.clone(),
+            PackageOrigin::Local(origin) => origin.commit.clone().unwrap_or_default(),
+        }
+    }
+
+    /// The version of the package, parsed according to the versioning scheme of its ecosystem.
+    pub fn semantic_version(&self) -> SemanticVersion {
+        SemanticVersion::with_scheme(&self.version(), self.ecosystem().version_scheme())
+    }
+
+    /// Where the package comes from upstream, as recorded in the tags of the replicas.
+    pub fn upstream_url(&self) -> String {
+        match self {
+            PackageOrigin::Git(origin) => origin.git.clone(),
+            PackageOrigin::GoCache(origin) => format!("https://{}", origin.upstream),
+            PackageOrigin::CargoRegistry(origin) if origin.registry.contains("crates.io-index") => {
+                format!("https://crates.io/crates/{}", origin.name)
+            },
+            PackageOrigin::CargoRegistry(origin) => origin.registry.clone(),
+            PackageOrigin::PythonSdist(origin) => format!("https://pypi.org/project/{}/", origin.name),
+            PackageOrigin::Maven(origin) => origin.scm.clone().unwrap_or(origin.name()),
+            PackageOrigin::Local(origin) => origin.path.clone(),
+        }
+    }
+
+    /// The hash of the source code of the package, as recorded by its package manager.
+    pub fn hash(&self) -> Option<String> {
+        match self {
+            PackageOrigin::GoCache(origin) => origin.hash.clone(),
+            PackageOrigin::CargoRegistry(origin) => origin.checksum.clone(),
+            PackageOrigin::PythonSdist(origin) => origin.sha256.clone(),
+            _ => None,
+        }
+    }
+
+    /// The upstream commit the package was replicated from, when it is known.
+    pub fn commit(&self) -> Option<String> {
+        match self {
+            PackageOrigin::GoCache(origin) => origin.commit.clone(),
+            PackageOrigin::Local(origin) => origin.commit.clone(),
+            _ => None,
+        }
+    }
+
+    pub fn ecosystem(&self) -> Ecosystem {
+        match self {
+            PackageOrigin::CargoRegistry(_) => Ecosystem::Rust,
+            PackageOrigin::PythonSdist(_) => Ecosystem::Python,
+            PackageOrigin::Maven(_) => Ecosystem::Java,
+            _ => Ecosystem::Go,
+        }
+    }
 }
@@ -0,0 +1,23 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageOriginCargoRegistry {
+    pub name: String,
+    pub version: String,
+    pub registry: String,
+    pub checksum: Option<String>,
+    #[serde(default)]
+    pub path: String,
+}
+
+impl PackageOriginCargoRegistry {
+    pub fn new(
+        name: String,
+        version: String,
+        registry: String,
+        checksum: Option<String>,
+        path: String,
+    ) -> Self {
+        PackageOriginCargoRegistry { name, version, registry, checksum, path }
+    }
+}
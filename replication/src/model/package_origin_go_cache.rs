@@ -6,12 +6,23 @@ use crate::model::package_origin::PackageOrigin;
 pub struct PackageOriginGoCache {
     pub name: String,
     pub version: String,
+    #[serde(default)]
     pub path: String,
+    #[serde(default)]
     pub upstream: String,
+    pub hash: Option<String>,
+    pub commit: Option<String>,
 }
 
 impl PackageOriginGoCache {
-    pub fn new(name: String, version: String, path: String, upstream: String) -> PackageOrigin {
-        PackageOrigin::GoCache(PackageOriginGoCache { name, version, path, upstream })
+    pub fn new(
+        name: String,
+        version: String,
+        path: String,
+        upstream: String,
+        hash: Option<String>,
+        commit: Option<String>,
+    ) -> PackageOrigin {
+        PackageOrigin::GoCache(PackageOriginGoCache { name, version, path, upstream, hash, commit })
     }
 }
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageOriginLocal {
+    pub path: String,
+    pub commit: Option<String>,
+}
+
+impl PackageOriginLocal {
+    pub fn new(path: String, commit: Option<String>) -> Self {
+        PackageOriginLocal { path, commit }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageOriginMaven {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub sources_jar: String,
+    pub pom: Option<String>,
+    pub scm: Option<String>,
+}
+
+impl PackageOriginMaven {
+    pub fn new(
+        group_id: String,
+        artifact_id: String,
+        version: String,
+        sources_jar: String,
+        pom: Option<String>,
+        scm: Option<String>,
+    ) -> Self {
+        PackageOriginMaven { group_id, artifact_id, version, sources_jar, pom, scm }
+    }
+
+    /// The `groupId.artifactId` name of the artifact.
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.group_id, self.artifact_id)
+    }
+}
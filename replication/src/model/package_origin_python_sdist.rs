@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageOriginPythonSdist {
+    pub name: String,
+    pub version: String,
+    pub sdist: String,
+    pub sha256: Option<String>,
+}
+
+impl PackageOriginPythonSdist {
+    pub fn new(
+        name: String,
+        version: String,
+        sdist: String,
+        sha256: Option<String>,
+    ) -> Self {
+        PackageOriginPythonSdist { name, version, sdist, sha256 }
+    }
+}
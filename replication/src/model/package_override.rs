@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::{
+    sanitized_name::SanitizedName,
+    semantic_version::SemanticVersion
+};
+
+use crate::model::{
+    package_destination::PackageDestination,
+    package_destination_bundle::PackageDestinationBundle,
+    package_destination_directory::PackageDestinationDirectory,
+    package_destination_git::PackageDestinationGit,
+    target::Target
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackageOverride {
+    #[serde(default)]
+    pub exclude: bool,
+    pub version: Option<String>,
+
+    pub git: Option<String>,
+    pub reference: Option<String>,
+    pub directory: Option<String>,
+    pub bundle: Option<String>,
+
+    pub is_library: Option<bool>,
+
+    pub build_snaps: Option<Vec<String>>,
+    pub build_environment: Option<Vec<HashMap<String, String>>>,
+}
+
+impl PackageOverride {
+    pub fn matches(pattern: &str, module: &str) -> bool {
+        if pattern == module {
+            return true;
+        }
+
+        let pattern: String = regex::escape(pattern)
+            .replace("\\*", ".*")
+            .replace("\\?", ".");
+
+        Regex::new(&format!("^{}$", pattern))
+            .map(|pattern| pattern.is_match(module))
+            .unwrap_or(false)
+    }
+
+    pub fn apply_destination(
+        &self,
+        destination: PackageDestination,
+        name: &SanitizedName,
+        version: &SemanticVersion,
+        target: &Target,
+    ) -> PackageDestination {
+        let apply = |template: &String| target.apply(&version.apply(&name.apply(template)));
+
+        match destination {
+            PackageDestination::Git(destination) => {
+                PackageDestinationGit::new(
+                    self.git.as_ref().map(apply).unwrap_or(destination.git),
+                    self.reference.as_ref().map(apply).unwrap_or(destination.reference),
+                    destination.tag,
+                )
+            },
+            PackageDestination::Directory(destination) => {
+                PackageDestination::Directory(
+                    PackageDestinationDirectory::new(
+                        self.directory.as_ref().map(apply).unwrap_or(destination.directory),
+                        self.reference.as_ref().map(apply).unwrap_or(destination.reference),
+                        destination.bare,
+                        destination.tag,
+                    )
+                )
+            },
+            PackageDestination::Bundle(destination) => {
+                PackageDestination::Bundle(
+                    PackageDestinationBundle::new(
+                        self.bundle.as_ref().map(apply).unwrap_or(destination.bundle),
+                        self.reference.as_ref().map(apply).unwrap_or(destination.reference),
+                        destination.tag,
+                    )
+                )
+            },
+            destination => destination,
+        }
+    }
+}
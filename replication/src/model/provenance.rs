@@ -0,0 +1,44 @@
+use serde::{Serialize, Deserialize};
+
+/// What a replicated commit was made from, recorded as trailers of its message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    pub upstream_url: String,
+    pub upstream_version: String,
+    pub module_hash: Option<String>,
+    pub source_wand_version: String,
+    pub plan_hash: String,
+}
+
+impl Provenance {
+    pub fn new(
+        upstream_url: String,
+        upstream_version: String,
+        module_hash: Option<String>,
+        plan_hash: String,
+    ) -> Self {
+        Provenance {
+            upstream_url,
+            upstream_version,
+            module_hash,
+            source_wand_version: env!("CARGO_PKG_VERSION").to_string(),
+            plan_hash,
+        }
+    }
+
+    pub fn trailers(&self) -> Vec<(String, String)> {
+        let mut trailers: Vec<(String, String)> = vec![
+            ("Upstream-URL".to_string(), self.upstream_url.clone()),
+            ("Upstream-Version".to_string(), self.upstream_version.clone()),
+        ];
+
+        if let Some(module_hash) = &self.module_hash {
+            trailers.push(("Module-Hash".to_string(), module_hash.clone()));
+        }
+
+        trailers.push(("Source-Wand-Version".to_string(), self.source_wand_version.clone()));
+        trailers.push(("Plan-Hash".to_string(), self.plan_hash.clone()));
+
+        trailers
+    }
+}
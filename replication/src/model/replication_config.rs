@@ -1,12 +1,69 @@
 use serde::{Serialize, Deserialize};
 
+use crate::model::{naming::Naming, target::Target};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplicationConfig {
     pub git_identity: Option<GitIdentity>,
+    pub fetch_strategy: Option<FetchStrategy>,
+    pub patches: Option<String>,
+    pub target: Option<Target>,
+    pub targets: Option<Vec<Target>>,
+    pub metadata: Option<MetadataFormat>,
+    pub python_index: Option<String>,
+    pub maven_repository: Option<String>,
+    pub naming: Option<Naming>,
+}
+
+impl ReplicationConfig {
+    /// Every target to replicate for, `target` coming first.
+    pub fn targets(&self) -> Vec<Target> {
+        self.target
+            .iter()
+            .chain(self.targets.iter().flatten())
+            .cloned()
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitIdentity {
     pub username: String,
     pub email: String,
+    pub signing_key: Option<String>,
+    pub signing_format: Option<SigningFormat>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SigningFormat {
+    #[default]
+    Gpg,
+    Ssh,
+}
+
+impl SigningFormat {
+    /// The value of the `gpg.format` git configuration.
+    pub fn git_format(&self) -> &str {
+        match self {
+            SigningFormat::Gpg => "openpgp",
+            SigningFormat::Ssh => "ssh",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FetchStrategy {
+    #[default]
+    ModuleCache,
+    UpstreamHistory,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataFormat {
+    #[default]
+    Sourcecraft,
+    Debian,
 }
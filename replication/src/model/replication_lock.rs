@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+
+use crate::model::replication_plan::ReplicationPlan;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationLock {
+    pub manifest_sha256: String,
+    pub plan: ReplicationPlan,
+}
+
+impl ReplicationLock {
+    pub fn new(manifest_sha256: String, plan: ReplicationPlan) -> Self {
+        ReplicationLock { manifest_sha256, plan }
+    }
+}
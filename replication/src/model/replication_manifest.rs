@@ -1,6 +1,17 @@
+use std::collections::BTreeMap;
+
 use serde::{Serialize, Deserialize};
+use source_wand_common::identity::naming_scheme::NamingScheme;
 
-use crate::model::{hooks::Hooks, package_destination::PackageDestination, package_origin::PackageOrigin, replication_config::ReplicationConfig};
+use crate::model::{
+    ecosystem::Ecosystem,
+    hooks::Hooks,
+    package_destination::PackageDestination,
+    package_origin::PackageOrigin,
+    package_override::PackageOverride,
+    replication_config::ReplicationConfig,
+    target::Target
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplicationManifest {
@@ -8,6 +19,7 @@ pub struct ReplicationManifest {
     pub hooks: Option<Hooks>,
     pub origin: PackageOrigin,
     pub destination_template: PackageDestination,
+    pub overrides: Option<BTreeMap<String, PackageOverride>>,
 
     pub config: Option<ReplicationConfig>,
 }
@@ -18,8 +30,42 @@ impl ReplicationManifest {
         hooks: Option<Hooks>,
         origin: PackageOrigin,
         destination_template: PackageDestination,
+        overrides: Option<BTreeMap<String, PackageOverride>>,
         config: Option<ReplicationConfig>,
     ) -> Self {
-        ReplicationManifest { project, hooks, origin, destination_template, config }
+        ReplicationManifest { project, hooks, origin, destination_template, overrides, config }
+    }
+
+    pub fn targets(&self) -> Vec<Target> {
+        let targets: Vec<Target> = self.config
+            .as_ref()
+            .map(|config| config.targets())
+            .unwrap_or_default();
+
+        if targets.is_empty() {
+            vec![Target::default()]
+        }
+        else {
+            targets
+        }
+    }
+
+    pub fn naming_scheme(&self, ecosystem: Ecosystem) -> NamingScheme {
+        self.config
+            .as_ref()
+            .and_then(|config| config.naming.as_ref())
+            .map(|naming| naming.scheme(ecosystem))
+            .unwrap_or(ecosystem.naming_scheme())
+    }
+
+    pub fn find_override(&self, module: &str) -> Option<&PackageOverride> {
+        let overrides: &BTreeMap<String, PackageOverride> = self.overrides.as_ref()?;
+
+        overrides.get(module).or_else(|| {
+            overrides
+                .iter()
+                .find(|(pattern, _)| PackageOverride::matches(pattern, module))
+                .map(|(_, package_override)| package_override)
+        })
     }
 }
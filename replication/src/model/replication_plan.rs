@@ -1,6 +1,19 @@
+use std::collections::BTreeMap;
+
 use serde::{Serialize, Deserialize};
+use source_wand_common::identity::{
+    naming_scheme::NamingScheme,
+    sanitized_name::SanitizedName
+};
 
-use crate::model::{hooks::Hooks, package::Package, replication_config::ReplicationConfig};
+use crate::model::{
+    ecosystem::Ecosystem,
+    hooks::Hooks,
+    package::Package,
+    package_origin::PackageOrigin,
+    replication_config::ReplicationConfig,
+    target::Target
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplicationPlan {
@@ -9,6 +22,10 @@ pub struct ReplicationPlan {
     pub packages: Vec<Package>,
 
     pub config: Option<ReplicationConfig>,
+
+    /// The sanitized name of every package, by original name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub names: BTreeMap<String, String>,
 }
 
 impl ReplicationPlan {
@@ -18,6 +35,38 @@ impl ReplicationPlan {
         packages: Vec<Package>,
         config: Option<ReplicationConfig>,
     ) -> Self {
-        ReplicationPlan { project, hooks, packages, config }
+        ReplicationPlan { project, hooks, packages, config, names: BTreeMap::new() }
+    }
+
+    /// The target a package was planned for.
+    pub fn target(&self, package: &Package) -> Target {
+        package.target
+            .clone()
+            .or_else(|| self.config.as_ref().and_then(|config| config.targets().first().cloned()))
+            .unwrap_or_default()
+    }
+
+    pub fn naming_scheme(&self, ecosystem: Ecosystem) -> NamingScheme {
+        self.config
+            .as_ref()
+            .and_then(|config| config.naming.as_ref())
+            .map(|naming| naming.scheme(ecosystem))
+            .unwrap_or(ecosystem.naming_scheme())
+    }
+
+    /// The name of a package as recorded when it was planned.
+    pub fn sanitized_name(&self, origin: &PackageOrigin) -> SanitizedName {
+        // The top-level project is not named by the planners.
+        if let PackageOrigin::Git(_) | PackageOrigin::Local(_) = origin {
+            return SanitizedName::new(&origin.name());
+        }
+
+        let ecosystem: Ecosystem = origin.ecosystem();
+        let original: String = ecosystem.normalize(&origin.name());
+
+        match self.names.get(&origin.name()) {
+            Some(sanitized) => SanitizedName::from_mapping(&original, sanitized),
+            None => self.naming_scheme(ecosystem).sanitize(&original),
+        }
     }
 }
@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct GoPart {
     pub plugin: String,
     pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub build_snaps: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub build_environment: Option<Vec<HashMap<String, String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub after: Option<Vec<String>>,
 }
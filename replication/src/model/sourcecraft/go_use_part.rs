@@ -1,9 +1,14 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "kebab-case")]
+use crate::model::sourcecraft::grammar::Grammar;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct GoUsePart {
     pub plugin: String,
-    pub source: String,
+    pub source: Grammar<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Vec<String>>,
 }
@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Grammar<T> {
+    Value(T),
+    Conditional(Vec<HashMap<String, T>>),
+}
+
+impl<T> Grammar<T> {
+    pub fn for_platforms(value: T, platforms: &Option<Vec<String>>) -> Self {
+        match platforms {
+            Some(platforms) => {
+                let mut statement: HashMap<String, T> = HashMap::new();
+                statement.insert(format!("to {}", platforms.join(",")), value);
+                Grammar::Conditional(vec![statement])
+            },
+            None => Grammar::Value(value),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::sourcecraft::grammar::Grammar;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MavenUsePart {
+    pub plugin: String,
+    pub source: Grammar<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Vec<String>>,
+}
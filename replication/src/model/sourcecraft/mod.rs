@@ -1,7 +1,14 @@
 pub mod sourcecraft_metadata;
 
+pub mod grammar;
+pub mod platform;
 pub mod part;
 
 pub mod nil_part;
 pub mod go_part;
 pub mod go_use_part;
+pub mod rust_part;
+pub mod cargo_use_part;
+pub mod python_part;
+pub mod maven_part;
+pub mod maven_use_part;
@@ -1,8 +1,18 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
 
-use crate::model::sourcecraft::{go_part::GoPart, go_use_part::GoUsePart, nil_part::NilPart};
+use crate::model::sourcecraft::{
+    cargo_use_part::CargoUsePart,
+    go_part::GoPart,
+    go_use_part::GoUsePart,
+    grammar::Grammar,
+    maven_part::MavenPart,
+    maven_use_part::MavenUsePart,
+    nil_part::NilPart,
+    python_part::PythonPart,
+    rust_part::RustPart
+};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
@@ -10,6 +20,38 @@ pub enum Part {
     Nil(NilPart),
     Go(GoPart),
     GoUse(GoUsePart),
+    Rust(RustPart),
+    CargoUse(CargoUsePart),
+    Python(PythonPart),
+    Maven(MavenPart),
+    MavenUse(MavenUsePart),
+    Other(serde_yaml::Value),
+}
+
+impl<'de> Deserialize<'de> for Part {
+    /// Parts of the plugins generated by source-wand are read strictly, so that a typo is reported
+    /// instead of the part being mistaken for one that was added by hand.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        let value: serde_yaml::Value = serde_yaml::Value::deserialize(deserializer)?;
+        let plugin: String = value.get("plugin")
+            .and_then(|plugin| plugin.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let part: serde_yaml::Result<Part> = match plugin.as_str() {
+            "go" => serde_yaml::from_value(value).map(Part::Go),
+            "go-use" => serde_yaml::from_value(value).map(Part::GoUse),
+            "rust" => serde_yaml::from_value(value).map(Part::Rust),
+            "cargo-use" => serde_yaml::from_value(value).map(Part::CargoUse),
+            "python" => serde_yaml::from_value(value).map(Part::Python),
+            "maven" => serde_yaml::from_value(value).map(Part::Maven),
+            "maven-use" => serde_yaml::from_value(value).map(Part::MavenUse),
+            _ => Ok(Part::Other(value)),
+        };
+
+        part.map_err(|e| D::Error::custom(format!("invalid {} part: {}", plugin, e)))
+    }
 }
 
 impl Part {
@@ -37,12 +79,180 @@ impl Part {
     pub fn with_go_use_plugin(
         name: String,
         track: String,
+        risk: String,
+        platforms: &Option<Vec<String>>,
     ) -> Self {
         Part::GoUse(
             GoUsePart {
                 plugin: "go-use".to_string(),
-                source: format!("sourcecraft:{}", name),
-                source_channel: Some(format!("{}/edge", track)),
+                source: Grammar::for_platforms(format!("sourcecraft:{}", name), platforms),
+                source_channel: Some(format!("{}/{}", track, risk)),
+                after: None,
+            }
+        )
+    }
+
+    pub fn with_rust_plugin(
+        source: String,
+        build_snaps: Vec<String>,
+        build_environment: Vec<HashMap<String, String>>,
+        after: Vec<String>,
+    ) -> Self {
+        Part::Rust(
+            RustPart {
+                plugin: "rust".to_string(),
+                source,
+                build_snaps: Some(build_snaps),
+                build_environment: Some(build_environment),
+                after: Some(after),
+            }
+        )
+    }
+
+    pub fn with_cargo_use_plugin(
+        name: String,
+        track: String,
+        risk: String,
+        platforms: &Option<Vec<String>>,
+    ) -> Self {
+        Part::CargoUse(
+            CargoUsePart {
+                plugin: "cargo-use".to_string(),
+                source: Grammar::for_platforms(format!("sourcecraft:{}", name), platforms),
+                source_channel: Some(format!("{}/{}", track, risk)),
+                after: None,
+            }
+        )
+    }
+
+    pub fn with_local_cargo_use_plugin(
+        source: String,
+        after: Vec<String>,
+    ) -> Self {
+        Part::CargoUse(
+            CargoUsePart {
+                plugin: "cargo-use".to_string(),
+                source: Grammar::Value(source),
+                source_channel: None,
+                after: Some(after),
+            }
+        )
+    }
+
+    pub fn with_python_plugin(
+        source: String,
+        build_snaps: Vec<String>,
+        build_environment: Vec<HashMap<String, String>>,
+        after: Vec<String>,
+    ) -> Self {
+        Part::Python(
+            PythonPart {
+                plugin: "python".to_string(),
+                source: Grammar::Value(source),
+                source_channel: None,
+                build_snaps: Some(build_snaps),
+                build_environment: Some(build_environment),
+                after: Some(after),
+            }
+        )
+    }
+
+    /// There is no plugin to consume Python packages from sourcecraft, so dependencies are built from their replica.
+    pub fn with_python_dependency_plugin(
+        name: String,
+        track: String,
+        risk: String,
+        platforms: &Option<Vec<String>>,
+    ) -> Self {
+        Part::Python(
+            PythonPart {
+                plugin: "python".to_string(),
+                source: Grammar::for_platforms(format!("sourcecraft:{}", name), platforms),
+                source_channel: Some(format!("{}/{}", track, risk)),
+                build_snaps: None,
+                build_environment: None,
+                after: None,
+            }
+        )
+    }
+
+    pub fn with_maven_plugin(
+        source: String,
+        build_snaps: Vec<String>,
+        build_environment: Vec<HashMap<String, String>>,
+        after: Vec<String>,
+    ) -> Self {
+        Part::Maven(
+            MavenPart {
+                plugin: "maven".to_string(),
+                source,
+                build_snaps: Some(build_snaps),
+                build_environment: Some(build_environment),
+                after: Some(after),
+            }
+        )
+    }
+
+    pub fn with_maven_use_plugin(
+        name: String,
+        track: String,
+        risk: String,
+        platforms: &Option<Vec<String>>,
+    ) -> Self {
+        Part::MavenUse(
+            MavenUsePart {
+                plugin: "maven-use".to_string(),
+                source: Grammar::for_platforms(format!("sourcecraft:{}", name), platforms),
+                source_channel: Some(format!("{}/{}", track, risk)),
+                after: None,
+            }
+        )
+    }
+
+    pub fn with_local_maven_use_plugin(
+        source: String,
+        after: Vec<String>,
+    ) -> Self {
+        Part::MavenUse(
+            MavenUsePart {
+                plugin: "maven-use".to_string(),
+                source: Grammar::Value(source),
+                source_channel: None,
+                after: Some(after),
+            }
+        )
+    }
+
+    /// Whether this part pulls the given package from sourcecraft, as generated for dependencies.
+    pub fn is_use_of(&self, name: &String) -> bool {
+        let source: String = format!("sourcecraft:{}", name);
+
+        let part_source: &Grammar<String> = match self {
+            Part::GoUse(part) => &part.source,
+            Part::CargoUse(part) => &part.source,
+            Part::Python(part) => &part.source,
+            Part::MavenUse(part) => &part.source,
+            _ => return false,
+        };
+
+        match part_source {
+            Grammar::Value(value) => *value == source,
+            Grammar::Conditional(statements) => {
+                statements.iter().any(|statement| statement.values().any(|value| *value == source))
+            },
+        }
+    }
+
+    pub fn with_local_go_use_plugin(
+        source: String,
+        after: Vec<String>,
+    ) -> Self {
+        Part::GoUse(
+            GoUsePart {
+                plugin: "go-use".to_string(),
+                source: Grammar::Value(source),
+                source_channel: None,
+                after: Some(after),
             }
         )
     }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Platform {
+    #[serde(default)]
+    pub build_on: Vec<String>,
+    #[serde(default)]
+    pub build_for: Vec<String>,
+}
+
+impl Platform {
+    pub fn native(architecture: &str) -> Self {
+        Platform {
+            build_on: vec![architecture.to_string()],
+            build_for: vec![architecture.to_string()],
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::sourcecraft::grammar::Grammar;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PythonPart {
+    pub plugin: String,
+    pub source: Grammar<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_snaps: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_environment: Option<Vec<HashMap<String, String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Vec<String>>,
+}
@@ -1,16 +1,87 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::model::sourcecraft::part::Part;
+use crate::model::sourcecraft::{part::Part, platform::Platform};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourcecraftMetadata {
     pub name: String,
     pub version: String,
     pub base: String,
     pub summary: String,
     pub description: String,
-    pub platforms: HashMap<String, ()>,
-    pub parts: HashMap<String, Part>
+    /// A platform without details (`amd64: null`) is built on and for itself.
+    pub platforms: BTreeMap<String, Option<Platform>>,
+    pub parts: BTreeMap<String, Part>,
+
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
+}
+
+impl SourcecraftMetadata {
+    /// Regenerates the parts managed by source-wand (the package itself and the go-use
+    /// parts of its dependencies) while keeping the parts and keys that were added by hand.
+    pub fn merge(&self, existing: &SourcecraftMetadata) -> SourcecraftMetadata {
+        let mut merged: SourcecraftMetadata = self.clone();
+
+        for (key, value) in &existing.extra {
+            merged.extra.entry(key.clone()).or_insert(value.clone());
+        }
+
+        for (name, part) in &existing.parts {
+            let is_managed: bool = *name == existing.name || part.is_use_of(name);
+
+            if !is_managed {
+                merged.parts.entry(name.clone()).or_insert(part.clone());
+            }
+        }
+
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_platforms_without_details() {
+        let metadata: SourcecraftMetadata = serde_yaml::from_str(
+            "name: go-github-com-spf13-cobra
+version: v1.9.1
+base: ubuntu@24.04
+summary: cobra
+description: cobra
+platforms:
+  amd64: null
+  arm64:
+    build-on: [arm64]
+parts:
+  go-github-com-spf13-cobra:
+    plugin: nil
+    source: .
+"
+        ).unwrap();
+
+        assert!(metadata.platforms["amd64"].is_none());
+        assert_eq!(metadata.platforms["arm64"].as_ref().unwrap().build_on, vec!["arm64".to_string()]);
+        assert!(metadata.platforms["arm64"].as_ref().unwrap().build_for.is_empty());
+    }
+
+    #[test]
+    fn keeps_parts_of_other_plugins() {
+        let part: Part = serde_yaml::from_str("plugin: dump\nsource: .\nstage-packages: [git]\n").unwrap();
+
+        assert!(matches!(part, Part::Other(_)));
+    }
+
+    #[test]
+    fn reports_typos_in_generated_parts() {
+        let error: serde_yaml::Error = serde_yaml::from_str::<Part>(
+            "plugin: go-use\nsource: sourcecraft:go-golang-org-x-sys\nsource-chanel: 0-24.04/edge\n"
+        ).unwrap_err();
+
+        assert!(error.to_string().contains("source-chanel"));
+    }
 }
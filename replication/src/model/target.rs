@@ -0,0 +1,65 @@
+use serde::{Serialize, Deserialize};
+use source_wand_common::identity::semantic_version::SemanticVersion;
+
+/// Every field left out of the manifest keeps its default value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Target {
+    pub base: String,
+    pub series: String,
+    pub risk: String,
+    pub platforms: Vec<String>,
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target {
+            base: "ubuntu".to_string(),
+            series: "24.04".to_string(),
+            risk: "edge".to_string(),
+            platforms: vec!["amd64".to_string()],
+        }
+    }
+}
+
+impl Target {
+    pub fn base(&self) -> String {
+        format!("{}@{}", self.base, self.series)
+    }
+
+    pub fn track(&self, version: &SemanticVersion) -> String {
+        format!("{}-{}", version.retrocompatible, self.series)
+    }
+
+    pub fn channel(&self, version: &SemanticVersion) -> String {
+        format!("{}/{}", self.track(version), self.risk)
+    }
+
+    pub fn goarch(platform: &str) -> &str {
+        match platform {
+            "ppc64el" => "ppc64le",
+            "armhf" => "arm",
+            "i386" => "386",
+            platform => platform,
+        }
+    }
+
+    pub fn rust_target(platform: &str) -> String {
+        match platform {
+            "amd64" => "x86_64-unknown-linux-gnu",
+            "arm64" => "aarch64-unknown-linux-gnu",
+            "armhf" => "armv7-unknown-linux-gnueabihf",
+            "i386" => "i686-unknown-linux-gnu",
+            "ppc64el" => "powerpc64le-unknown-linux-gnu",
+            "riscv64" => "riscv64gc-unknown-linux-gnu",
+            "s390x" => "s390x-unknown-linux-gnu",
+            platform => return format!("{}-unknown-linux-gnu", platform),
+        }.to_string()
+    }
+
+    pub fn apply(&self, template: &str) -> String {
+        template
+            .replace("$BASE", &self.base)
+            .replace("$SERIES", &self.series)
+    }
+}
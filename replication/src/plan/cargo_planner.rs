@@ -0,0 +1,263 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    path::{Path, PathBuf}
+};
+
+use anyhow::{bail, Result};
+use colorize::AnsiColor;
+use serde_json::Value;
+use source_wand_common::{
+    identity::{
+        sanitized_name::SanitizedName,
+        semantic_version::SemanticVersion
+    },
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+
+use crate::{
+    model::{
+        dependency::Dependency,
+        ecosystem::Ecosystem,
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        package_origin_cargo_registry::PackageOriginCargoRegistry,
+        package_override::PackageOverride,
+        replication_manifest::ReplicationManifest,
+        target::Target
+    },
+    plan::naming::NameRegistry
+};
+
+pub fn is_cargo_project(sh: &LocalProjectManipulator) -> bool {
+    sh.project_root.join("Cargo.toml").exists() && !sh.project_root.join("go.mod").exists()
+}
+
+/// Plans the replication of every crate needed to build a Cargo project, as locked by its `Cargo.lock`.
+pub fn plan_cargo_packages(
+    top_level: &LocalProjectManipulator,
+    replication_manifest: &ReplicationManifest,
+    target: &Target,
+    names: &NameRegistry,
+) -> Result<Vec<Package>> {
+    if !top_level.project_root.join("Cargo.lock").exists() {
+        top_level.run_shell("cargo generate-lockfile".to_string())?;
+    }
+
+    let lock: HashMap<(String, String), LockedCrate> = read_cargo_lock(top_level)?;
+
+    let mut crates: BTreeMap<String, CargoCrate> = BTreeMap::new();
+    let mut platforms_by_dependency: HashMap<(String, String), Vec<String>> = HashMap::new();
+
+    for platform in &target.platforms {
+        let metadata: Value = serde_json::from_str(
+            top_level.run_shell(
+                format!(
+                    "cargo metadata --format-version 1 --locked --filter-platform {}",
+                    Target::rust_target(platform),
+                )
+            )?.as_str()
+        )?;
+
+        let workspace_members: HashSet<String> = metadata["workspace_members"]
+            .as_array()
+            .unwrap_or(&Vec::new())
+            .iter()
+            .filter_map(|member| member.as_str().map(|member| member.to_string()))
+            .collect();
+
+        let mut dependencies_by_id: HashMap<String, Vec<String>> = HashMap::new();
+        for node in metadata["resolve"]["nodes"].as_array().unwrap_or(&Vec::new()) {
+            let dependencies: Vec<String> = node["deps"]
+                .as_array()
+                .unwrap_or(&Vec::new())
+                .iter()
+                .filter(|dependency| is_build_dependency(dependency))
+                .filter_map(|dependency| dependency["pkg"].as_str().map(|id| id.to_string()))
+                .collect();
+
+            dependencies_by_id.insert(node["id"].as_str().unwrap_or_default().to_string(), dependencies);
+        }
+
+        let mut queue: VecDeque<String> = workspace_members.iter().cloned().collect();
+        let mut reachable: HashSet<String> = HashSet::new();
+        while let Some(id) = queue.pop_front() {
+            if reachable.insert(id.clone()) {
+                queue.extend(dependencies_by_id.get(&id).cloned().unwrap_or_default());
+            }
+        }
+
+        for package in metadata["packages"].as_array().unwrap_or(&Vec::new()) {
+            let id: String = package["id"].as_str().unwrap_or_default().to_string();
+            if !reachable.contains(&id) {
+                continue;
+            }
+
+            let cargo_crate: &mut CargoCrate = crates.entry(id.clone()).or_insert(
+                CargoCrate {
+                    name: package["name"].as_str().unwrap_or_default().to_string(),
+                    version: package["version"].as_str().unwrap_or_default().to_string(),
+                    source: package["source"].as_str().map(|source| source.to_string()),
+                    manifest_path: package["manifest_path"].as_str().unwrap_or_default().to_string(),
+                    is_workspace_member: workspace_members.contains(&id),
+                    dependencies: Vec::new(),
+                }
+            );
+
+            for dependency in dependencies_by_id.get(&id).cloned().unwrap_or_default() {
+                if !cargo_crate.dependencies.contains(&dependency) {
+                    cargo_crate.dependencies.push(dependency.clone());
+                }
+
+                platforms_by_dependency.entry((id.clone(), dependency)).or_default().push(platform.clone());
+            }
+        }
+    }
+
+    let mut packages: Vec<Package> = Vec::new();
+
+    for (id, cargo_crate) in &crates {
+        let package_override: PackageOverride = replication_manifest.find_override(&cargo_crate.name)
+            .cloned()
+            .unwrap_or_default();
+
+        if package_override.exclude {
+            println!(
+                "{} excluding {} from the replication",
+                "[plan]".green(),
+                cargo_crate.name.clone().italic(),
+            );
+            continue;
+        }
+
+        if let Some(pinned_version) = &package_override.version {
+            if pinned_version != &cargo_crate.version {
+                bail!(
+                    "cannot pin {} to {}, versions of Rust crates are pinned by Cargo.lock",
+                    cargo_crate.name,
+                    pinned_version,
+                );
+            }
+        }
+
+        let name: SanitizedName = names.name(&cargo_crate.name);
+        let version: SemanticVersion = SemanticVersion::with_scheme(&cargo_crate.version, Ecosystem::Rust.version_scheme());
+
+        let package_destination: PackageDestination = package_override.apply_destination(
+            replication_manifest.destination_template.apply(&name, &version, target),
+            &name,
+            &version,
+            target,
+        );
+
+        let dependencies: Vec<Dependency> = cargo_crate.dependencies
+            .iter()
+            .filter_map(|dependency_id| crates.get(dependency_id).map(|dependency| (dependency_id, dependency)))
+            .map(|(dependency_id, dependency)| {
+                let platforms: Vec<String> = platforms_by_dependency
+                    .get(&(id.clone(), dependency_id.clone()))
+                    .cloned()
+                    .unwrap_or_default();
+
+                Dependency {
+                    name: names.name(&dependency.name).sanitized.clone(),
+                    version: target.track(&SemanticVersion::with_scheme(&dependency.version, Ecosystem::Rust.version_scheme())),
+                    platforms: if platforms.len() < target.platforms.len() { Some(platforms) } else { None },
+                }
+            })
+            .collect();
+
+        let locked_crate: Option<&LockedCrate> = lock.get(&(cargo_crate.name.clone(), cargo_crate.version.clone()));
+
+        let package: Package = Package::new(
+            PackageOrigin::CargoRegistry(
+                PackageOriginCargoRegistry::new(
+                    cargo_crate.name.clone(),
+                    cargo_crate.version.clone(),
+                    cargo_crate.source.clone().unwrap_or("local".to_string()),
+                    locked_crate.and_then(|locked_crate| locked_crate.checksum.clone()),
+                    cargo_crate.source_path(),
+                )
+            ),
+            package_destination,
+            dependencies,
+            package_override.is_library.unwrap_or(!cargo_crate.is_workspace_member),
+            package_override.build_snaps.unwrap_or_default(),
+            package_override.build_environment.unwrap_or_default(),
+        );
+
+        packages.push(package);
+    }
+
+    Ok(packages)
+}
+
+struct LockedCrate {
+    checksum: Option<String>,
+}
+
+struct CargoCrate {
+    name: String,
+    version: String,
+    source: Option<String>,
+    manifest_path: String,
+    is_workspace_member: bool,
+    dependencies: Vec<String>,
+}
+
+impl CargoCrate {
+    /// The `.crate` archive of the registry cache if there is one, the unpacked sources otherwise
+    /// (i.e. the registry sources or the output of `cargo vendor`).
+    fn source_path(&self) -> String {
+        let directory: PathBuf = Path::new(&self.manifest_path)
+            .parent()
+            .map(|directory| directory.to_path_buf())
+            .unwrap_or_default();
+        let directory: String = directory.to_str().unwrap_or_default().to_string();
+
+        if directory.contains("/registry/src/") {
+            let crate_file: String = format!("{}.crate", directory.replace("/registry/src/", "/registry/cache/"));
+            if Path::new(&crate_file).exists() {
+                return crate_file;
+            }
+        }
+
+        directory
+    }
+}
+
+/// Dev-dependencies are not needed to build a crate, so they are not replicated.
+fn is_build_dependency(dependency: &Value) -> bool {
+    match dependency["dep_kinds"].as_array() {
+        Some(kinds) => kinds.iter().any(|kind| kind["kind"].as_str() != Some("dev")),
+        None => true,
+    }
+}
+
+fn read_cargo_lock(sh: &LocalProjectManipulator) -> Result<HashMap<(String, String), LockedCrate>> {
+    let mut locked_crates: HashMap<(String, String), LockedCrate> = HashMap::new();
+
+    let cargo_lock: String = sh.run_shell("cat Cargo.lock".to_string())?;
+
+    for entry in cargo_lock.split("[[package]]").skip(1) {
+        let mut fields: HashMap<&str, String> = HashMap::new();
+
+        for line in entry.lines() {
+            if let Some((key, value)) = line.split_once(" = ") {
+                fields.insert(key.trim(), value.trim().trim_matches('"').to_string());
+            }
+        }
+
+        if let (Some(name), Some(version)) = (fields.get("name"), fields.get("version")) {
+            locked_crates.insert(
+                (name.clone(), version.clone()),
+                LockedCrate { checksum: fields.get("checksum").cloned() },
+            );
+        }
+    }
+
+    Ok(locked_crates)
+}
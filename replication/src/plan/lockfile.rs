@@ -0,0 +1,116 @@
+use std::{fs::{canonicalize, read_to_string}, path::{Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+use source_wand_common::utils::{
+    read_yaml_file::read_yaml_file,
+    write_yaml_file::write_yaml_file
+};
+
+use crate::model::{
+    replication_lock::ReplicationLock,
+    replication_plan::ReplicationPlan
+};
+
+pub const REPLICATION_MANIFEST: &str = "replication.yaml";
+pub const REPLICATION_LOCKFILE: &str = "replication.lock.yaml";
+
+/// The directory of the replication manifest, which relative paths of the manifest are resolved against.
+pub fn manifest_directory() -> PathBuf {
+    canonicalize(REPLICATION_MANIFEST)
+        .ok()
+        .and_then(|manifest| manifest.parent().map(|directory| directory.to_path_buf()))
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default()
+}
+
+pub fn lock_replication_plan(replication_plan: &ReplicationPlan) -> Result<()> {
+    let replication_lock: ReplicationLock = ReplicationLock::new(
+        hash_replication_manifest()?,
+        replication_plan.clone(),
+    );
+
+    write_yaml_file(&replication_lock, REPLICATION_LOCKFILE)
+}
+
+pub fn read_locked_replication_plan() -> Result<ReplicationPlan> {
+    if !Path::new(REPLICATION_LOCKFILE).exists() {
+        bail!(
+            "{} does not exist, run \"source-wand plan\" to create it",
+            REPLICATION_LOCKFILE,
+        );
+    }
+
+    let replication_lock: ReplicationLock = read_yaml_file(REPLICATION_LOCKFILE)?;
+
+    if is_stale(&replication_lock, &read_to_string(REPLICATION_MANIFEST)?) {
+        bail!(
+            "{} is stale, {} changed since it was planned, run \"source-wand plan\" again",
+            REPLICATION_LOCKFILE,
+            REPLICATION_MANIFEST,
+        );
+    }
+
+    Ok(replication_lock.plan)
+}
+
+/// Reads the locked replication plan even if it is stale, so that a new plan can be compared to it.
+pub fn read_previous_replication_plan() -> Result<ReplicationPlan> {
+    if !Path::new(REPLICATION_LOCKFILE).exists() {
+        bail!(
+            "{} does not exist, there is no previous replication to upgrade from",
+            REPLICATION_LOCKFILE,
+        );
+    }
+
+    let replication_lock: ReplicationLock = read_yaml_file(REPLICATION_LOCKFILE)?;
+
+    Ok(replication_lock.plan)
+}
+
+/// A lockfile is stale when the manifest it was planned from has changed since.
+fn is_stale(replication_lock: &ReplicationLock, manifest: &str) -> bool {
+    replication_lock.manifest_sha256 != sha256(manifest)
+}
+
+fn hash_replication_manifest() -> Result<String> {
+    Ok(sha256(&read_to_string(REPLICATION_MANIFEST)?))
+}
+
+/// The SHA-256 digest of a text, as printed by `sha256sum`.
+fn sha256(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+/// Hashes a replication plan, so that replicated commits can be traced back to the plan they were made from.
+pub fn hash_replication_plan(replication_plan: &ReplicationPlan) -> Result<String> {
+    Ok(sha256(&serde_yaml::to_string(replication_plan)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_like_sha256sum() {
+        assert_eq!(sha256(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(sha256("project: demo\n"), "34505d5445f4a6212ec3e770031a777e48bad7a33dc2e12e8bec9206aa396930");
+    }
+
+    #[test]
+    fn detects_stale_lockfiles() {
+        let plan: ReplicationPlan = ReplicationPlan::new("demo".to_string(), None, Vec::new(), None);
+        let replication_lock: ReplicationLock = ReplicationLock::new(sha256("project: demo\n"), plan);
+
+        let cases: Vec<(&str, bool)> = vec![
+            ("project: demo\n", false),
+            ("project: demo\nhooks:\n  before_all: make\n", true),
+            ("project: other\n", true),
+            ("", true),
+        ];
+
+        for (manifest, stale) in cases {
+            assert_eq!(is_stale(&replication_lock, manifest), stale, "{:?}", manifest);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{canonicalize, read_to_string},
+    path::{Path, PathBuf}
+};
+
+use anyhow::Result;
+use colorize::AnsiColor;
+use regex::Regex;
+use source_wand_common::{
+    identity::{
+        sanitized_name::SanitizedName,
+        semantic_version::SemanticVersion
+    },
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+use uuid::Uuid;
+
+use crate::{
+    model::{
+        dependency::Dependency,
+        ecosystem::Ecosystem,
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        package_origin_maven::PackageOriginMaven,
+        package_override::PackageOverride,
+        replication_manifest::ReplicationManifest,
+        target::Target
+    },
+    plan::naming::NameRegistry
+};
+
+pub fn is_maven_project(sh: &LocalProjectManipulator) -> bool {
+    sh.project_root.join("pom.xml").exists()
+}
+
+/// Plans the replication of every artifact needed by a Maven project from the sources jars
+/// of the local Maven repository. Maven runs offline, so the local repository must already
+/// contain the artifacts and their sources (i.e. `mvn dependency:sources`).
+pub fn plan_maven_packages(
+    top_level: &LocalProjectManipulator,
+    replication_manifest: &ReplicationManifest,
+    target: &Target,
+    names: &NameRegistry,
+) -> Result<Vec<Package>> {
+    let repository: PathBuf = match replication_manifest.config.as_ref().and_then(|config| config.maven_repository.clone()) {
+        Some(repository) => canonicalize(repository)?,
+        None => PathBuf::from(std::env::var("HOME")?).join(".m2/repository"),
+    };
+
+    // The tree has to be resolved from the repository the sources jars are looked up in.
+    let tree_file: String = format!("/tmp/source-wand-{}.tgf", Uuid::new_v4());
+    top_level.run_shell(
+        format!(
+            "mvn -o -B -q -Dmaven.repo.local=\"{}\" dependency:tree -DoutputType=tgf -DappendOutput=true -DoutputFile={}",
+            repository.to_str().unwrap_or_default(),
+            tree_file,
+        )
+    )?;
+    let tree: String = read_to_string(&tree_file)?;
+    std::fs::remove_file(&tree_file)?;
+
+    let (artifacts, edges) = parse_tgf(&tree);
+
+    let targets: HashSet<&String> = edges.iter().map(|(_, to)| to).collect();
+    let mut dependencies_by_artifact: BTreeMap<Artifact, Vec<Artifact>> = BTreeMap::new();
+
+    for (id, artifact) in &artifacts {
+        let entry: &mut Vec<Artifact> = dependencies_by_artifact.entry(artifact.clone()).or_default();
+
+        for (from, to) in &edges {
+            if from != id {
+                continue;
+            }
+
+            if let Some(dependency) = artifacts.get(to) {
+                if !entry.contains(dependency) {
+                    entry.push(dependency.clone());
+                }
+            }
+        }
+    }
+
+    let modules: HashSet<Artifact> = artifacts
+        .iter()
+        .filter(|(id, _)| !targets.contains(id))
+        .map(|(_, artifact)| artifact.clone())
+        .collect();
+
+    let mut packages: Vec<Package> = Vec::new();
+
+    for (artifact, dependencies) in &dependencies_by_artifact {
+        let name: String = artifact.name();
+
+        let package_override: PackageOverride = replication_manifest.find_override(&name)
+            .cloned()
+            .unwrap_or_default();
+
+        if package_override.exclude {
+            println!(
+                "{} excluding {} from the replication",
+                "[plan]".green(),
+                name.clone().italic(),
+            );
+            continue;
+        }
+
+        let version: String = package_override.version.clone().unwrap_or(artifact.version.clone());
+        let is_module: bool = modules.contains(artifact);
+
+        let (sources, pom) = if is_module {
+            match module_directory(top_level, artifact, modules.len()) {
+                Some(directory) => (directory, None),
+                None => {
+                    println!(
+                        "{} could not find the sources of module {}, skipping it",
+                        "[plan]".green(),
+                        name.clone().italic(),
+                    );
+                    continue;
+                },
+            }
+        }
+        else {
+            let directory: PathBuf = repository
+                .join(artifact.group_id.replace('.', "/"))
+                .join(&artifact.artifact_id)
+                .join(&version);
+
+            let sources_jar: PathBuf = directory.join(format!("{}-{}-sources.jar", artifact.artifact_id, version));
+            let pom: PathBuf = directory.join(format!("{}-{}.pom", artifact.artifact_id, version));
+
+            if !sources_jar.exists() {
+                println!(
+                    "{} no sources jar for {}:{} in {}, skipping it",
+                    "[plan]".green(),
+                    name.clone().italic(),
+                    version.clone().italic(),
+                    repository.to_str().unwrap_or_default(),
+                );
+                continue;
+            }
+
+            (
+                sources_jar.to_str().unwrap_or_default().to_string(),
+                Some(pom).filter(|pom| pom.exists()).map(|pom| pom.to_str().unwrap_or_default().to_string()),
+            )
+        };
+
+        let scm: Option<String> = pom.as_ref().and_then(|pom| read_scm_url(Path::new(pom)));
+
+        let sanitized_name: SanitizedName = names.name(&name);
+        let semantic_version: SemanticVersion = SemanticVersion::with_scheme(&version, Ecosystem::Java.version_scheme());
+
+        let package_destination: PackageDestination = package_override.apply_destination(
+            replication_manifest.destination_template.apply(&sanitized_name, &semantic_version, target),
+            &sanitized_name,
+            &semantic_version,
+            target,
+        );
+
+        let dependencies: Vec<Dependency> = dependencies
+            .iter()
+            .map(|dependency| {
+                // A pinned artifact replaces the resolved one, so every edge has to point to the pinned version.
+                let version: String = replication_manifest.find_override(&dependency.name())
+                    .and_then(|package_override| package_override.version.clone())
+                    .unwrap_or(dependency.version.clone());
+
+                Dependency {
+                    name: names.name(&dependency.name()).sanitized.clone(),
+                    version: target.track(&SemanticVersion::with_scheme(&version, Ecosystem::Java.version_scheme())),
+                    platforms: None,
+                }
+            })
+            .collect();
+
+        let package: Package = Package::new(
+            PackageOrigin::Maven(
+                PackageOriginMaven::new(
+                    artifact.group_id.clone(),
+                    artifact.artifact_id.clone(),
+                    version,
+                    sources,
+                    pom,
+                    scm,
+                )
+            ),
+            package_destination,
+            dependencies,
+            package_override.is_library.unwrap_or(!is_module),
+            package_override.build_snaps.unwrap_or_default(),
+            package_override.build_environment.unwrap_or_default(),
+        );
+
+        packages.push(package);
+    }
+
+    Ok(packages)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Artifact {
+    group_id: String,
+    artifact_id: String,
+    version: String,
+}
+
+impl Artifact {
+    fn name(&self) -> String {
+        format!("{}.{}", self.group_id, self.artifact_id)
+    }
+}
+
+/// Parses the trivial graph format output of `mvn dependency:tree`, ignoring test dependencies.
+/// The output of every module of a multi-module project is appended to the same file.
+fn parse_tgf(tree: &str) -> (HashMap<String, Artifact>, Vec<(String, String)>) {
+    let mut artifacts: HashMap<String, Artifact> = HashMap::new();
+    let mut edges: Vec<(String, String)> = Vec::new();
+
+    for line in tree.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            [id, coordinates] if coordinates.contains(':') => {
+                let coordinates: Vec<&str> = coordinates.split(':').collect();
+                let (version, scope) = match coordinates.len() {
+                    4 => (coordinates[3], ""),
+                    5 => (coordinates[3], coordinates[4]),
+                    6 => (coordinates[4], coordinates[5]),
+                    _ => continue,
+                };
+
+                if scope == "test" {
+                    continue;
+                }
+
+                artifacts.insert(
+                    id.to_string(),
+                    Artifact {
+                        group_id: coordinates[0].to_string(),
+                        artifact_id: coordinates[1].to_string(),
+                        version: version.to_string(),
+                    },
+                );
+            },
+            [from, to] => edges.push((from.to_string(), to.to_string())),
+            [from, to, scope] if *scope != "test" => edges.push((from.to_string(), to.to_string())),
+            _ => {},
+        }
+    }
+
+    (artifacts, edges)
+}
+
+fn module_directory(top_level: &LocalProjectManipulator, artifact: &Artifact, modules: usize) -> Option<String> {
+    let project_root: PathBuf = top_level.project_root.canonicalize().ok()?;
+
+    let directory: PathBuf = if modules == 1 {
+        project_root
+    }
+    else {
+        project_root.join(&artifact.artifact_id)
+    };
+
+    if directory.join("pom.xml").exists() {
+        Some(directory.to_str().unwrap_or_default().to_string())
+    }
+    else {
+        None
+    }
+}
+
+fn read_scm_url(pom: &Path) -> Option<String> {
+    let pom: String = read_to_string(pom).ok()?;
+    let scm: Regex = Regex::new(r"(?s)<scm>.*?<url>\s*(.*?)\s*</url>.*?</scm>").unwrap();
+
+    scm.captures(&pom).map(|caps| caps[1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dependency_trees() {
+        let tree: &str = "\
+            1 com.example:app:jar:1.0.0\n\
+            2 com.google.guava:guava:jar:33.0.0-jre:compile\n\
+            3 junit:junit:jar:4.13.2:test\n\
+            4 org.slf4j:slf4j-api:jar:jdk8:2.0.9:runtime\n\
+            #\n\
+            1 2 compile\n\
+            1 3 test\n\
+            2 4\n";
+
+        let (artifacts, edges) = parse_tgf(tree);
+
+        let mut names: Vec<String> = artifacts
+            .values()
+            .map(|artifact| format!("{}:{}", artifact.name(), artifact.version))
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                "com.example.app:1.0.0",
+                "com.google.guava.guava:33.0.0-jre",
+                "org.slf4j.slf4j-api:2.0.9",
+            ],
+        );
+        assert_eq!(edges, vec![("1".to_string(), "2".to_string()), ("2".to_string(), "4".to_string())]);
+    }
+}
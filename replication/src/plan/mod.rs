@@ -1,2 +1,9 @@
 pub mod transformations;
 pub mod planner;
+pub mod cargo_planner;
+pub mod python_planner;
+pub mod maven_planner;
+pub mod lockfile;
+pub mod naming;
+pub mod validation;
+pub mod upgrade;
@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, sync::{Mutex, MutexGuard}};
+
+use source_wand_common::identity::{
+    naming_scheme::NamingScheme,
+    sanitized_name::SanitizedName
+};
+
+use crate::model::ecosystem::Ecosystem;
+
+/// Gives every package of a plan a unique sanitized name, keeping the names of the previous plan.
+pub struct NameRegistry {
+    ecosystem: Ecosystem,
+    scheme: NamingScheme,
+    recorded: BTreeMap<String, String>,
+    names: Mutex<BTreeMap<String, String>>,
+}
+
+impl NameRegistry {
+    pub fn new(ecosystem: Ecosystem, scheme: NamingScheme, recorded: BTreeMap<String, String>) -> Self {
+        NameRegistry { ecosystem, scheme, recorded, names: Mutex::new(BTreeMap::new()) }
+    }
+
+    pub fn name(&self, original: &String) -> SanitizedName {
+        let mut names: MutexGuard<'_, BTreeMap<String, String>> = self.names.lock().unwrap();
+        let normalized: String = self.ecosystem.normalize(original);
+
+        if let Some(sanitized) = names.get(original) {
+            return SanitizedName::from_mapping(&normalized, sanitized);
+        }
+
+        let plain: SanitizedName = self.scheme.sanitize(&normalized);
+        let disambiguated: SanitizedName = self.scheme.disambiguate(&plain);
+
+        // Names that only differ by their spelling (i.e. `PyYAML` and `pyyaml`) are the same package.
+        let is_taken = |candidate: &String| {
+            names.iter()
+                .chain(self.recorded.iter())
+                .any(|(other, sanitized)| self.ecosystem.normalize(other) != normalized && sanitized == candidate)
+        };
+
+        // A name recorded by the previous plan is kept as long as the scheme would still give it,
+        // so that a collision appearing in a later plan does not rename an existing replica.
+        let recorded: Option<&String> = self.recorded
+            .get(original)
+            .filter(|recorded| {
+                (*recorded == &plain.sanitized || *recorded == &disambiguated.sanitized) && !is_taken(recorded)
+            });
+
+        let sanitized: SanitizedName = match recorded {
+            Some(recorded) => SanitizedName::from_mapping(&normalized, recorded),
+            None if is_taken(&plain.sanitized) && self.scheme.hash_suffix => disambiguated,
+            None => plain,
+        };
+
+        names.insert(original.clone(), sanitized.sanitized.clone());
+
+        sanitized
+    }
+
+    /// The original name to sanitized name mapping, to be recorded in the plan.
+    pub fn names(&self) -> BTreeMap<String, String> {
+        self.names.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(ecosystem: Ecosystem, recorded: Vec<(&str, &str)>) -> NameRegistry {
+        NameRegistry::new(
+            ecosystem,
+            ecosystem.naming_scheme(),
+            recorded
+                .into_iter()
+                .map(|(original, sanitized)| (original.to_string(), sanitized.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn gives_the_same_name_to_every_spelling_of_a_package() {
+        let names: NameRegistry = registry(Ecosystem::Python, Vec::new());
+
+        let package: SanitizedName = names.name(&"PyYAML".to_string());
+        let dependency: SanitizedName = names.name(&"pyyaml".to_string());
+
+        assert_eq!(package.sanitized, "python-pyyaml");
+        assert_eq!(dependency.sanitized, package.sanitized);
+    }
+
+    const FIRST: &str = "github.com/first-organization/shared-project/internal/yaml-parser";
+    const SECOND: &str = "github.com/second-organization/shared-project/internal/yaml-parser";
+
+    #[test]
+    fn disambiguates_packages_that_would_share_a_name() {
+        let names: NameRegistry = registry(Ecosystem::Go, Vec::new());
+
+        let first: SanitizedName = names.name(&FIRST.to_string());
+        let second: SanitizedName = names.name(&SECOND.to_string());
+
+        let (base, suffix) = second.sanitized.split_at(second.sanitized.len() - 9);
+
+        assert!(first.sanitized.starts_with(base), "{} {}", first.sanitized, second.sanitized);
+        assert!(suffix.starts_with('-') && suffix[1..].chars().all(|c| c.is_ascii_hexdigit()), "{}", second.sanitized);
+        assert!(second.sanitized.len() <= 40, "{}", second.sanitized);
+        assert_ne!(first.sanitized, second.sanitized);
+    }
+
+    #[test]
+    fn gives_the_same_name_every_time_a_package_is_named() {
+        let names: NameRegistry = registry(Ecosystem::Go, Vec::new());
+
+        let first: SanitizedName = names.name(&FIRST.to_string());
+        let second: SanitizedName = names.name(&SECOND.to_string());
+
+        assert_eq!(names.name(&FIRST.to_string()).sanitized, first.sanitized);
+        assert_eq!(names.name(&SECOND.to_string()).sanitized, second.sanitized);
+        assert_eq!(names.names().len(), 2);
+    }
+
+    #[test]
+    fn keeps_the_names_recorded_by_the_previous_plan() {
+        let previous: NameRegistry = registry(Ecosystem::Go, Vec::new());
+        previous.name(&FIRST.to_string());
+        let disambiguated: String = previous.name(&SECOND.to_string()).sanitized.clone();
+
+        // The package that caused the collision is no longer part of the plan.
+        let names: NameRegistry = registry(Ecosystem::Go, vec![(SECOND, disambiguated.as_str())]);
+
+        assert_eq!(names.name(&SECOND.to_string()).sanitized, disambiguated);
+    }
+
+    #[test]
+    fn does_not_rename_a_recorded_package_when_a_collision_appears() {
+        let previous: NameRegistry = registry(Ecosystem::Go, Vec::new());
+        let plain: String = previous.name(&SECOND.to_string()).sanitized.clone();
+
+        let names: NameRegistry = registry(Ecosystem::Go, vec![(SECOND, plain.as_str())]);
+        let first: SanitizedName = names.name(&FIRST.to_string());
+
+        assert_eq!(names.name(&SECOND.to_string()).sanitized, plain);
+        assert_ne!(first.sanitized, plain);
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
-    collections::HashSet,
-    fs::create_dir_all,
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{canonicalize, create_dir_all},
     path::PathBuf,
     sync::{
         Arc,
@@ -30,216 +30,532 @@ use source_wand_dependency_analysis::{
 };
 use uuid::Uuid;
 
-use crate::model::{
-    dependency::Dependency,
-    package::Package,
-    package_destination::PackageDestination,
-    package_destination_git::PackageDestinationGit,
-    package_origin::PackageOrigin,
-    package_origin_go_cache::PackageOriginGoCache,
-    replication_manifest::ReplicationManifest,
-    replication_plan::ReplicationPlan
+use crate::{
+    model::{
+        dependency::Dependency,
+        ecosystem::Ecosystem,
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        package_origin_go_cache::PackageOriginGoCache,
+        package_override::PackageOverride,
+        replication_manifest::ReplicationManifest,
+        replication_plan::ReplicationPlan,
+        target::Target
+    },
+    plan::{
+        cargo_planner::{is_cargo_project, plan_cargo_packages},
+        lockfile::{read_previous_replication_plan, REPLICATION_MANIFEST},
+        maven_planner::{is_maven_project, plan_maven_packages},
+        naming::NameRegistry,
+        python_planner::{is_python_project, plan_python_packages},
+        validation::{validate_replication_plan, PlanProblem}
+    }
 };
 
+/// Plans the packages of a project that is not a Go module.
+type EcosystemPlanner = fn(&LocalProjectManipulator, &ReplicationManifest, &Target, &NameRegistry) -> Result<Vec<Package>>;
+
 pub fn plan_replication() -> Result<ReplicationPlan> {
-    println!(
-        "{} analyzing the origin project's dependency tree",
-        "[plan]".green(),
+    let replication_plan: ReplicationPlan = plan_unvalidated_replication()?;
+    let problems: Vec<PlanProblem> = validate_replication_plan(&replication_plan);
+
+    if problems.is_empty() {
+        return Ok(replication_plan);
+    }
+
+    for problem in &problems {
+        println!(
+            "{} {}",
+            "[plan]".red(),
+            problem.message,
+        );
+
+        for module in &problem.modules {
+            println!(
+                "{}   - {}",
+                "[plan]".red(),
+                module.clone().italic(),
+            );
+        }
+    }
+
+    bail!("the replication plan has {} problems", problems.len())
+}
+
+fn plan_unvalidated_replication() -> Result<ReplicationPlan> {
+    let replication_manifest: ReplicationManifest = read_yaml_file(REPLICATION_MANIFEST)?;
+    let targets: Vec<Target> = replication_manifest.targets();
+
+    let recorded_names: BTreeMap<String, String> = read_previous_replication_plan()
+        .map(|plan| plan.names)
+        .unwrap_or_default();
+    let mut names: BTreeMap<String, String> = BTreeMap::new();
+    let mut packages: Vec<Package> = Vec::new();
+
+    for target in &targets {
+        println!(
+            "{} analyzing the origin project's dependency tree for {}",
+            "[plan]".green(),
+            target.base().italic(),
+        );
+
+        // The names given for a previous target are recorded too, so that a package keeps its name across targets.
+        let mut recorded_names: BTreeMap<String, String> = recorded_names.clone();
+        recorded_names.extend(names.clone());
+
+        let (target_packages, target_names) = plan_target_packages(&replication_manifest, target, recorded_names)?;
+
+        names.extend(target_names);
+        packages.extend(
+            target_packages.into_iter().map(|mut package| {
+                if targets.len() > 1 {
+                    package.target = Some(target.clone());
+                }
+                package
+            })
+        );
+    }
+
+    let mut replication_plan: ReplicationPlan = ReplicationPlan::new(
+        replication_manifest.project,
+        replication_manifest.hooks,
+        packages,
+        replication_manifest.config,
     );
+    replication_plan.names = names;
 
-    let replication_manifest: ReplicationManifest = read_yaml_file("replication.yaml")?;
+    Ok(replication_plan)
+}
 
-    match replication_manifest.origin {
+fn plan_target_packages(
+    replication_manifest: &ReplicationManifest,
+    target: &Target,
+    recorded_names: BTreeMap<String, String>,
+) -> Result<(Vec<Package>, BTreeMap<String, String>)> {
+    let (top_level, dependency_tree_request, top_level_module) = match &replication_manifest.origin {
         PackageOrigin::Git(origin) => {
-            let uuid: Uuid = Uuid::new_v4();
-            let top_level_directory: PathBuf = PathBuf::from(format!("./source-wand/{}", uuid));
-            create_dir_all(&top_level_directory)?;
-    
-            let top_level: LocalProjectManipulator = LocalProjectManipulator::new(top_level_directory, true);
+            let top_level: LocalProjectManipulator = create_scratch_project()?;
 
             top_level.run_shell(format!("git clone {} .", origin.git))?;
             top_level.run_shell(format!("git checkout {}", origin.reference))?;
 
-            top_level.run_shell("go mod download all".to_string())?;
-
-            let mut packages: Vec<Package> = Vec::new();
+            let commit: String = top_level.run_shell("git rev-parse HEAD".to_string())?
+                .trim()
+                .to_string();
 
-            let dependency_tree: Arc<Mutex<DependencyTreeNode>> = find_dependency_tree(
+            (
+                top_level,
                 DependencyTreeRequest::from_git_project(
                     origin.git.clone(),
                     Some(origin.reference.clone())
-                )
-            )?;
-
-            let build_dependencies_whitelist: HashSet<(String, String)> = {
-                let raw: serde_json::Value = serde_json::from_str(
-                    top_level.run_shell(
-                        "go mod edit -json".to_string()
-                    )?.as_str()
-                )?;
-
-                if let Value::Object(module) = raw {
-                    let module_name: &String = match module.get("Module") {
-                        Some(Value::Object(module)) => {
-                            match module.get("Path") {
-                                Some(Value::String(module_name)) => {
-                                    &module_name
-                                        .replace("/", "-")
-                                        .replace(".", "-")
-                                },
-                                _ => bail!("Dependencies whitelist does not contain Module -> Path field"),
-                            }
-                        },
-                        _ => bail!("Dependencies whitelist does not contain Module field"),
-                    };
-
-                    let module_version: String = origin.reference
+                ),
+                TopLevelModule {
+                    version: origin.reference
                         .split('/')
                         .last()
                         .unwrap_or_default()
-                        .to_string();
-
-                    let dependencies: Vec<(String, String)> = match module.get("Require") {
-                        Some(Value::Array(dependencies)) => {
-                            let mut dependencies: Vec<(String, String)> = dependencies.iter()
-                                .map(
-                                    |dependency| {
-                                        match dependency {
-                                            Value::Object(dependency) => {
-                                                let name: &String = match dependency.get("Path") {
-                                                    Some(Value::String(path)) => {
-                                                        &path
-                                                            .replace("/", "-")
-                                                            .replace(".", "-")
-                                                    },
-                                                    _ => bail!("Dependencies whitelist Require[i].Path was not a String"),
-                                                };
-                                                let version: &String = match dependency.get("Version") {
-                                                    Some(Value::String(version)) => version,
-                                                    _ => bail!("Dependencies whitelist Require[i].Version was not a String"),
-                                                };
-
-                                                Ok((name.clone(), version.clone()))
-                                            },
-                                            _ => bail!("Dependencies whitelist Require[i] field was not an object"),
-                                        }
-                                    }
-                                )
-                                .collect::<Result<Vec<_>, anyhow::Error>>()?;
+                        .to_string(),
+                    path: None,
+                    hash: None,
+                    commit: Some(commit),
+                },
+            )
+        },
+        PackageOrigin::GoCache(origin) => {
+            let module: &String = if origin.upstream.is_empty() { &origin.name } else { &origin.upstream };
 
-                            dependencies.push((module_name.clone(), module_version.clone()));
+            let top_level: LocalProjectManipulator = create_scratch_project()?;
 
-                            dependencies
-                        },
-                        _ => bail!("Dependencies whitelist does not contain Require field")
-                    };
+            top_level.run_shell("go mod init source-wand-scratch".to_string())?;
 
-                    dependencies.into_iter().collect::<HashSet<(String, String)>>()
-                }
-                else {
-                    bail!("Dependencies whitelist is not an object")
-                }
+            let (cache_path, hash) = download_go_module(&top_level, module, &origin.version)?;
+
+            top_level.run_shell("rm -f go.mod go.sum".to_string())?;
+            top_level.run_shell(format!("cp -r {}/. .", cache_path))?;
+            top_level.run_shell("chmod -R u+w .".to_string())?;
+
+            (
+                top_level.clone(),
+                DependencyTreeRequest::from_local_project(top_level.project_root.clone()),
+                TopLevelModule {
+                    version: origin.version.clone(),
+                    path: Some(cache_path),
+                    hash,
+                    commit: None,
+                },
+            )
+        },
+        PackageOrigin::CargoRegistry(origin) => {
+            bail!(
+                "cannot replicate {} from a Cargo registry, use a git or local origin for Rust projects",
+                origin.name,
+            );
+        },
+        PackageOrigin::PythonSdist(origin) => {
+            bail!(
+                "cannot replicate {} from an sdist, use a git or local origin for Python projects",
+                origin.name,
+            );
+        },
+        PackageOrigin::Maven(origin) => {
+            bail!(
+                "cannot replicate {} from a Maven repository, use a git or local origin for Java projects",
+                origin.name(),
+            );
+        },
+        PackageOrigin::Local(origin) => {
+            let working_tree: LocalProjectManipulator = LocalProjectManipulator::new(canonicalize(&origin.path)?, false);
+            let revision: String = origin.commit.clone().unwrap_or("HEAD".to_string());
+
+            let commit: Option<String> = working_tree.run_shell(format!("git rev-parse {}^{{commit}}", revision))
+                .ok()
+                .map(|commit| commit.trim().to_string());
+
+            let version: String = working_tree.run_shell(format!("git describe --tags --always {}", revision))
+                .map(|version| version.trim().to_string())
+                .unwrap_or_default();
+
+            let project_root: PathBuf = match (&origin.commit, &commit) {
+                (Some(_), Some(commit)) => snapshot_local_commit(&working_tree, commit)?,
+                (Some(requested), None) => bail!("{} is not a commit of {}", requested, origin.path),
+                (None, _) => working_tree.project_root.clone(),
             };
+            let top_level: LocalProjectManipulator = LocalProjectManipulator::new(project_root.clone(), false);
+
+            (
+                top_level,
+                DependencyTreeRequest::from_local_project(project_root.clone()),
+                TopLevelModule {
+                    version,
+                    path: Some(project_root.to_str().unwrap_or_default().to_string()),
+                    hash: None,
+                    commit,
+                },
+            )
+        },
+    };
+
+    let (ecosystem, plan_packages): (Ecosystem, Option<EcosystemPlanner>) = if is_cargo_project(&top_level) {
+        (Ecosystem::Rust, Some(plan_cargo_packages))
+    }
+    else if is_python_project(&top_level) {
+        (Ecosystem::Python, Some(plan_python_packages))
+    }
+    else if is_maven_project(&top_level) {
+        (Ecosystem::Java, Some(plan_maven_packages))
+    }
+    else {
+        (Ecosystem::Go, None)
+    };
+
+    let names: NameRegistry = NameRegistry::new(
+        ecosystem,
+        replication_manifest.naming_scheme(ecosystem),
+        recorded_names,
+    );
+
+    if let Some(plan_packages) = plan_packages {
+        let packages: Vec<Package> = plan_packages(&top_level, replication_manifest, target, &names)?;
+
+        top_level.cleanup();
+
+        return Ok((packages, names.names()));
+    }
+
+    top_level.run_shell("go mod download all".to_string())?;
+
+    let module_hashes: HashMap<(String, String), String> = top_level.run_shell("cat go.sum".to_string())
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [path, version, hash] if !version.ends_with("/go.mod") => {
+                    Some(((path.to_string(), version.to_string()), hash.to_string()))
+                },
+                _ => None,
+            }
+        })
+        .collect();
+
+    let platforms_by_module: HashMap<String, Vec<String>> = find_platforms_by_module(&top_level, target)?;
+
+    let mut packages: Vec<Package> = Vec::new();
+
+    let dependency_tree: Arc<Mutex<DependencyTreeNode>> = find_dependency_tree(dependency_tree_request)?;
 
-            let build_dependencies: serde_json::Value = serde_json::from_str(
-                top_level.run_shell(
-                    "go list -json -m all | jq -s".to_string()
-                )?.as_str()
-            )?;
-
-            if let Value::Array(build_dependencies) = build_dependencies {
-                for build_dependency in build_dependencies {
-                    if let Value::Object(build_dependency) = build_dependency {
-                        let name: String = build_dependency.get("Path")
-                            .unwrap()
-                            .as_str()
-                            .unwrap_or_default()
-                            .replace("/", "-")
-                            .replace(".", "-");
-
-                        let version: String = build_dependency.get("Version")
-                            .unwrap_or(
-                                &Value::String(origin.reference.split('/')
-                                    .last()
-                                    .unwrap_or_default()
-                                    .to_string()
-                                )
-                            )
-                            .as_str()
-                            .unwrap_or_default()
-                            .to_string();
-
-                        if !build_dependencies_whitelist.contains(&(name.clone(), version.clone())) {
-                            continue;
-                        }
-
-                        let cache_path: String = build_dependency.get("Dir")
-                            .unwrap_or(&Value::String(String::new()))
-                            .as_str()
-                            .unwrap_or_default()
-                            .to_string();
-
-                        let upstream: String = build_dependency.get("Path")
-                            .unwrap()
-                            .as_str()
-                            .unwrap_or_default()
-                            .to_string();
-
-                        let name: SanitizedName = SanitizedName::new(&name);
-                        let version: SemanticVersion = SemanticVersion::new(&version);
-
-                        let PackageDestination::Git(package_destination) = &replication_manifest.destination_template;
-
-                        let package_destination_url: String = name.apply(&package_destination.git);
-                        let package_destination_url: String = version.apply(&package_destination_url);
-
-                        let package_destination_reference: String = name.apply(&package_destination.reference);
-                        let package_destination_reference: String = version.apply(&package_destination_reference);
-
-                        let dependencies: Vec<Dependency> = find_dependencies_for_package(
-                            dependency_tree.clone(),
-                            &name.original,
-                        );
-
-                        let package: Package = Package::new(
-                            PackageOriginGoCache::new(
-                                name.sanitized.clone(),
-                                version.raw.clone(),
-                                cache_path,
-                                upstream,
-                            ),
-                            PackageDestinationGit::new(
-                                package_destination_url,
-                                package_destination_reference,
-                            ),
-                            dependencies,
-                            !origin.git.clone()
+    let build_dependencies_whitelist: HashSet<(String, String)> = {
+        let raw: serde_json::Value = serde_json::from_str(
+            top_level.run_shell(
+                "go mod edit -json".to_string()
+            )?.as_str()
+        )?;
+
+        if let Value::Object(module) = raw {
+            let module_name: &String = match module.get("Module") {
+                Some(Value::Object(module)) => {
+                    match module.get("Path") {
+                        Some(Value::String(module_name)) => {
+                            &module_name
                                 .replace("/", "-")
                                 .replace(".", "-")
-                                .ends_with(&name.sanitized)
-                        );
-
-                        packages.push(package);
+                        },
+                        _ => bail!("Dependencies whitelist does not contain Module -> Path field"),
                     }
+                },
+                _ => bail!("Dependencies whitelist does not contain Module field"),
+            };
+
+            let module_version: String = top_level_module.version.clone();
+
+            let dependencies: Vec<(String, String)> = match module.get("Require") {
+                Some(Value::Array(dependencies)) => {
+                    let mut dependencies: Vec<(String, String)> = dependencies.iter()
+                        .map(
+                            |dependency| {
+                                match dependency {
+                                    Value::Object(dependency) => {
+                                        let name: &String = match dependency.get("Path") {
+                                            Some(Value::String(path)) => {
+                                                &path
+                                                    .replace("/", "-")
+                                                    .replace(".", "-")
+                                            },
+                                            _ => bail!("Dependencies whitelist Require[i].Path was not a String"),
+                                        };
+                                        let version: &String = match dependency.get("Version") {
+                                            Some(Value::String(version)) => version,
+                                            _ => bail!("Dependencies whitelist Require[i].Version was not a String"),
+                                        };
+
+                                        Ok((name.clone(), version.clone()))
+                                    },
+                                    _ => bail!("Dependencies whitelist Require[i] field was not an object"),
+                                }
+                            }
+                        )
+                        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+                    dependencies.push((module_name.clone(), module_version.clone()));
+
+                    dependencies
+                },
+                _ => bail!("Dependencies whitelist does not contain Require field")
+            };
+
+            dependencies.into_iter().collect::<HashSet<(String, String)>>()
+        }
+        else {
+            bail!("Dependencies whitelist is not an object")
+        }
+    };
+
+    let build_dependencies: serde_json::Value = serde_json::from_str(
+        top_level.run_shell(
+            "go list -json -m all | jq -s".to_string()
+        )?.as_str()
+    )?;
+
+    if let Value::Array(build_dependencies) = build_dependencies {
+        for build_dependency in build_dependencies {
+            if let Value::Object(build_dependency) = build_dependency {
+                let name: String = build_dependency.get("Path")
+                    .unwrap()
+                    .as_str()
+                    .unwrap_or_default()
+                    .replace("/", "-")
+                    .replace(".", "-");
+
+                let version: String = build_dependency.get("Version")
+                    .unwrap_or(&Value::String(top_level_module.version.clone()))
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+
+                if !build_dependencies_whitelist.contains(&(name.clone(), version.clone())) {
+                    continue;
+                }
+
+                let is_main_module: bool = build_dependency.get("Main")
+                    .and_then(|main| main.as_bool())
+                    .unwrap_or(false);
+
+                let cache_path: String = match (is_main_module, &top_level_module.path) {
+                    (true, Some(path)) => path.clone(),
+                    _ => build_dependency.get("Dir")
+                        .unwrap_or(&Value::String(String::new()))
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                };
+
+                let upstream: String = build_dependency.get("Path")
+                    .unwrap()
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+
+                let hash: Option<String> = if is_main_module {
+                    top_level_module.hash.clone()
                 }
+                else {
+                    module_hashes.get(&(upstream.clone(), version.clone())).cloned()
+                };
+
+                let package_override: PackageOverride = replication_manifest.find_override(&upstream)
+                    .cloned()
+                    .unwrap_or_default();
+
+                if package_override.exclude {
+                    println!(
+                        "{} excluding {} from the replication",
+                        "[plan]".green(),
+                        upstream.clone().italic(),
+                    );
+                    continue;
+                }
+
+                let (version, cache_path, hash) = match &package_override.version {
+                    Some(pinned_version) if pinned_version != &version => {
+                        let (cache_path, hash) = download_go_module(&top_level, &upstream, pinned_version)?;
+                        (pinned_version.clone(), cache_path, hash)
+                    },
+                    _ => (version, cache_path, hash),
+                };
+
+                let name: SanitizedName = names.name(&upstream);
+                let version: SemanticVersion = SemanticVersion::new(&version);
+
+                let package_destination: PackageDestination = package_override.apply_destination(
+                    replication_manifest.destination_template.apply(&name, &version, target),
+                    &name,
+                    &version,
+                    target,
+                );
+
+                let dependencies: Vec<Dependency> = find_dependencies_for_package(
+                    dependency_tree.clone(),
+                    &name.original,
+                    target,
+                    &platforms_by_module,
+                    &names,
+                    replication_manifest,
+                );
+
+                let package: Package = Package::new(
+                    PackageOriginGoCache::new(
+                        name.sanitized.clone(),
+                        version.raw.clone(),
+                        cache_path,
+                        upstream,
+                        hash,
+                        if is_main_module { top_level_module.commit.clone() } else { None },
+                    ),
+                    package_destination,
+                    dependencies,
+                    package_override.is_library.unwrap_or(!is_main_module),
+                    package_override.build_snaps.unwrap_or_default(),
+                    package_override.build_environment.unwrap_or_default(),
+                );
+
+                packages.push(package);
             }
+        }
+    }
 
-            top_level.cleanup();
+    top_level.cleanup();
 
-            let replication_plan: ReplicationPlan = ReplicationPlan::new(
-                replication_manifest.project,
-                replication_manifest.hooks,
-                packages,
-                replication_manifest.config,
-            );
+    Ok((packages, names.names()))
+}
 
-            Ok(replication_plan)
-        },
-        PackageOrigin::GoCache(_origin) => { todo!() },
+fn find_platforms_by_module(
+    sh: &LocalProjectManipulator,
+    target: &Target,
+) -> Result<HashMap<String, Vec<String>>> {
+    let mut platforms_by_module: HashMap<String, Vec<String>> = HashMap::new();
+
+    for platform in &target.platforms {
+        let modules: String = sh.run_shell(
+            format!(
+                "GOOS=linux GOARCH={} go list -e -deps -f '{{{{with .Module}}}}{{{{.Path}}}}{{{{end}}}}' ./... | sort -u",
+                Target::goarch(platform),
+            )
+        )?;
+
+        for module in modules.lines().filter(|module| !module.is_empty()) {
+            platforms_by_module.entry(module.to_string()).or_default().push(platform.clone());
+        }
     }
+
+    Ok(platforms_by_module)
 }
 
-fn find_dependencies_for_package(root: Arc<Mutex<DependencyTreeNode>>, package_name: &str) -> Vec<Dependency> {
+fn download_go_module(
+    sh: &LocalProjectManipulator,
+    module: &String,
+    version: &String,
+) -> Result<(String, Option<String>)> {
+    let download: Value = serde_json::from_str(
+        sh.run_shell(
+            format!("go mod download -json {}@{}", module, version)
+        )?.as_str()
+    )?;
+
+    let cache_path: String = match download.get("Dir") {
+        Some(Value::String(cache_path)) => cache_path.clone(),
+        _ => bail!("Could not download {}@{} from the Go module proxy", module, version),
+    };
+
+    let hash: Option<String> = download.get("Sum")
+        .and_then(|sum| sum.as_str())
+        .map(|sum| sum.to_string());
+
+    Ok((cache_path, hash))
+}
+
+/// Extracts a commit of a local repository to a directory of its own, leaving the working tree untouched.
+/// The snapshot is kept, so that the replication can copy the source code from it later on.
+fn snapshot_local_commit(working_tree: &LocalProjectManipulator, commit: &String) -> Result<PathBuf> {
+    let snapshot: PathBuf = PathBuf::from(format!("./source-wand-cache/snapshots/{}", commit));
+
+    if !snapshot.exists() {
+        create_dir_all(&snapshot)?;
+        working_tree.run_shell(
+            format!(
+                "git archive {} | tar -x -C \"{}\"",
+                commit,
+                canonicalize(&snapshot)?.to_str().unwrap_or_default(),
+            )
+        )?;
+    }
+
+    Ok(canonicalize(snapshot)?)
+}
+
+pub fn create_scratch_project() -> Result<LocalProjectManipulator> {
+    let uuid: Uuid = Uuid::new_v4();
+    let top_level_directory: PathBuf = PathBuf::from(format!("./source-wand/{}", uuid));
+    create_dir_all(&top_level_directory)?;
+
+    Ok(LocalProjectManipulator::new(top_level_directory, true))
+}
+
+struct TopLevelModule {
+    version: String,
+    path: Option<String>,
+    hash: Option<String>,
+    commit: Option<String>,
+}
+
+fn find_dependencies_for_package(
+    root: Arc<Mutex<DependencyTreeNode>>,
+    package_name: &str,
+    target: &Target,
+    platforms_by_module: &HashMap<String, Vec<String>>,
+    names: &NameRegistry,
+    replication_manifest: &ReplicationManifest,
+) -> Vec<Dependency> {
     let (name, dependencies) = {
         let node: MutexGuard<'_, DependencyTreeNode> = root.lock().unwrap();
         (
@@ -254,19 +570,34 @@ fn find_dependencies_for_package(root: Arc<Mutex<DependencyTreeNode>>, package_n
             .map(|dep| {
                 let dep_guard: MutexGuard<'_, DependencyTreeNode> = dep.lock().unwrap();
 
-                let name: SanitizedName = SanitizedName::new(&dep_guard.project.name);
-                let version: SemanticVersion = SemanticVersion::new(&dep_guard.project.version);
+                // A pinned module replaces the resolved one, so every edge has to point to the pinned version.
+                let pinned_version: Option<&String> = replication_manifest.find_override(&dep_guard.project.name)
+                    .and_then(|package_override| package_override.version.as_ref());
+
+                let name: SanitizedName = names.name(&dep_guard.project.name);
+                let version: SemanticVersion = SemanticVersion::new(pinned_version.unwrap_or(&dep_guard.project.version));
 
                 Dependency {
-                    name: name.sanitized.replace("/", "-").replace(".", "-"),
-                    version: format!("{}-24.04", version.retrocompatible),
+                    name: name.sanitized.clone(),
+                    version: target.track(&version),
+                    platforms: match platforms_by_module.get(&dep_guard.project.name) {
+                        Some(platforms) if platforms.len() < target.platforms.len() => Some(platforms.clone()),
+                        _ => None,
+                    },
                 }
             })
             .collect();
     }
 
     for child in dependencies {
-        let found: Vec<Dependency> = find_dependencies_for_package(child, package_name);
+        let found: Vec<Dependency> = find_dependencies_for_package(
+            child,
+            package_name,
+            target,
+            platforms_by_module,
+            names,
+            replication_manifest,
+        );
         if !found.is_empty() {
             return found;
         }
@@ -0,0 +1,285 @@
+use std::{
+    collections::HashMap,
+    fs::{canonicalize, create_dir_all},
+    path::PathBuf
+};
+
+use anyhow::{bail, Result};
+use colorize::AnsiColor;
+use serde_json::Value;
+use source_wand_common::{
+    identity::{
+        sanitized_name::SanitizedName,
+        semantic_version::SemanticVersion
+    },
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+
+use crate::{
+    model::{
+        dependency::Dependency,
+        ecosystem::Ecosystem,
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        package_origin_python_sdist::PackageOriginPythonSdist,
+        package_override::PackageOverride,
+        replication_manifest::ReplicationManifest,
+        target::Target
+    },
+    plan::naming::NameRegistry
+};
+
+const SDISTS_DIRECTORY: &str = "source-wand-cache/sdists";
+
+pub fn is_python_project(sh: &LocalProjectManipulator) -> bool {
+    let has_requirements: bool = sh.project_root.join("requirements.txt").exists()
+        || sh.project_root.join("pyproject.toml").exists();
+
+    has_requirements
+        && !sh.project_root.join("go.mod").exists()
+        && !sh.project_root.join("Cargo.toml").exists()
+}
+
+/// Plans the replication of the sdist of every distribution needed by a Python project,
+/// as resolved by pip from its `requirements.txt` (or `pyproject.toml`).
+pub fn plan_python_packages(
+    top_level: &LocalProjectManipulator,
+    replication_manifest: &ReplicationManifest,
+    target: &Target,
+    names: &NameRegistry,
+) -> Result<Vec<Package>> {
+    let index_options: String = match replication_manifest.config.as_ref().and_then(|config| config.python_index.clone()) {
+        Some(index) if index.contains("://") => format!("--index-url {}", index),
+        Some(index) => format!("--no-index --find-links {}", canonicalize(&index)?.to_str().unwrap_or_default()),
+        None => String::new(),
+    };
+
+    let requirements: &str = if top_level.project_root.join("requirements.txt").exists() {
+        "-r requirements.txt"
+    }
+    else {
+        "."
+    };
+
+    let report: Value = serde_json::from_str(
+        top_level.run_shell(
+            format!(
+                "python3 -m pip install --dry-run --quiet --ignore-installed --no-binary :all: {} --report - {}",
+                index_options,
+                requirements,
+            )
+        )?.as_str()
+    )?;
+
+    let sdists_directory: PathBuf = PathBuf::from(SDISTS_DIRECTORY);
+    create_dir_all(&sdists_directory)?;
+    let sdists_directory: PathBuf = canonicalize(sdists_directory)?;
+
+    let distributions: Vec<&Value> = report["install"]
+        .as_array()
+        .map(|install| install.iter().collect())
+        .unwrap_or_default();
+
+    let versions: HashMap<String, String> = distributions
+        .iter()
+        .map(|distribution| (
+            normalize_name(distribution["metadata"]["name"].as_str().unwrap_or_default()),
+            distribution["metadata"]["version"].as_str().unwrap_or_default().to_string(),
+        ))
+        .collect();
+
+    let requested: Vec<String> = distributions
+        .iter()
+        .filter(|distribution| distribution["requested"].as_bool().unwrap_or_default())
+        .map(|distribution| normalize_name(distribution["metadata"]["name"].as_str().unwrap_or_default()))
+        .collect();
+
+    let mut packages: Vec<Package> = Vec::new();
+
+    for distribution in distributions {
+        let name: String = distribution["metadata"]["name"].as_str().unwrap_or_default().to_string();
+        let version: String = distribution["metadata"]["version"].as_str().unwrap_or_default().to_string();
+        let url: String = distribution["download_info"]["url"].as_str().unwrap_or_default().to_string();
+        let is_project: bool = distribution["download_info"].get("dir_info").is_some();
+
+        let package_override: PackageOverride = replication_manifest.find_override(&name)
+            .cloned()
+            .unwrap_or_default();
+
+        if package_override.exclude {
+            println!(
+                "{} excluding {} from the replication",
+                "[plan]".green(),
+                name.clone().italic(),
+            );
+            continue;
+        }
+
+        if let Some(pinned_version) = &package_override.version {
+            if pinned_version != &version {
+                bail!(
+                    "cannot pin {} to {}, pin it in the requirements of the project instead",
+                    name,
+                    pinned_version,
+                );
+            }
+        }
+
+        let sdist: String = match url.strip_prefix("file://") {
+            Some(path) => path.to_string(),
+            None => {
+                top_level.run_shell(
+                    format!(
+                        "python3 -m pip download --quiet --no-deps --no-binary :all: {} -d {} '{}=={}'",
+                        index_options,
+                        sdists_directory.to_str().unwrap_or_default(),
+                        name,
+                        version,
+                    )
+                )?;
+
+                let file: &str = url.rsplit('/').next().unwrap_or_default();
+                sdists_directory.join(file).to_str().unwrap_or_default().to_string()
+            },
+        };
+
+        let sha256: Option<String> = distribution["download_info"]["archive_info"]["hashes"]["sha256"]
+            .as_str()
+            .map(|sha256| sha256.to_string());
+
+        let sanitized_name: SanitizedName = names.name(&name);
+        let semantic_version: SemanticVersion = SemanticVersion::with_scheme(&version, Ecosystem::Python.version_scheme());
+
+        let package_destination: PackageDestination = package_override.apply_destination(
+            replication_manifest.destination_template.apply(&sanitized_name, &semantic_version, target),
+            &sanitized_name,
+            &semantic_version,
+            target,
+        );
+
+        let dependencies: Vec<Dependency> = distribution["metadata"]["requires_dist"]
+            .as_array()
+            .unwrap_or(&Vec::new())
+            .iter()
+            .filter_map(|requirement| requirement.as_str())
+            .filter(|requirement| !requirement.contains("extra =="))
+            .filter_map(|requirement| {
+                let dependency: String = requirement_name(requirement);
+                versions.get(&dependency).map(|version| Dependency {
+                    name: names.name(&dependency).sanitized.clone(),
+                    version: target.track(&SemanticVersion::with_scheme(version, Ecosystem::Python.version_scheme())),
+                    platforms: None,
+                })
+            })
+            .collect();
+
+        let package: Package = Package::new(
+            PackageOrigin::PythonSdist(PackageOriginPythonSdist::new(name, version, sdist, sha256)),
+            package_destination,
+            dependencies,
+            package_override.is_library.unwrap_or(!is_project),
+            package_override.build_snaps.unwrap_or_default(),
+            package_override.build_environment.unwrap_or_default(),
+        );
+
+        packages.push(package);
+    }
+
+    // A project that only has a requirements.txt is not a distribution pip reports on,
+    // it is replicated as the top-level package requiring the distributions it lists.
+    if requirements == "-r requirements.txt" {
+        packages.push(plan_requirements_project(top_level, replication_manifest, target, &requested, &versions, names));
+    }
+
+    Ok(packages)
+}
+
+fn plan_requirements_project(
+    top_level: &LocalProjectManipulator,
+    replication_manifest: &ReplicationManifest,
+    target: &Target,
+    requested: &[String],
+    versions: &HashMap<String, String>,
+    names: &NameRegistry,
+) -> Package {
+    let name: String = replication_manifest.project.clone();
+    let version: String = top_level.run_shell("git describe --tags --always".to_string())
+        .map(|version| version.trim().to_string())
+        .unwrap_or("0.0.0".to_string());
+
+    let package_override: PackageOverride = replication_manifest.find_override(&name)
+        .cloned()
+        .unwrap_or_default();
+
+    let sanitized_name: SanitizedName = names.name(&name);
+    let semantic_version: SemanticVersion = SemanticVersion::new(&version);
+
+    let package_destination: PackageDestination = package_override.apply_destination(
+        replication_manifest.destination_template.apply(&sanitized_name, &semantic_version, target),
+        &sanitized_name,
+        &semantic_version,
+        target,
+    );
+
+    let dependencies: Vec<Dependency> = requested
+        .iter()
+        .filter_map(|dependency| versions.get(dependency).map(|version| Dependency {
+            name: names.name(dependency).sanitized.clone(),
+            version: target.track(&SemanticVersion::with_scheme(version, Ecosystem::Python.version_scheme())),
+            platforms: None,
+        }))
+        .collect();
+
+    Package::new(
+        PackageOrigin::PythonSdist(
+            PackageOriginPythonSdist::new(
+                name,
+                version,
+                top_level.project_root.to_str().unwrap_or_default().to_string(),
+                None,
+            )
+        ),
+        package_destination,
+        dependencies,
+        false,
+        package_override.build_snaps.unwrap_or_default(),
+        package_override.build_environment.unwrap_or_default(),
+    )
+}
+
+/// Normalizes a distribution name as described by PEP 503.
+fn normalize_name(name: &str) -> String {
+    Ecosystem::Python.normalize(name)
+}
+
+fn requirement_name(requirement: &str) -> String {
+    let name: &str = requirement
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .next()
+        .unwrap_or_default();
+
+    normalize_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_requirement_names() {
+        let cases: Vec<(&str, &str)> = vec![
+            ("requests (>=2.0)", "requests"),
+            ("typing_extensions>=4.0; python_version < \"3.11\"", "typing-extensions"),
+            ("charset-normalizer<4,>=2", "charset-normalizer"),
+            ("Zope.Interface", "zope-interface"),
+        ];
+
+        for (requirement, expected) in cases {
+            assert_eq!(requirement_name(requirement), expected, "{}", requirement);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{bail, Result};
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct CargoFetchSource {
+    pub origin: String,
+    pub checksum: Option<String>,
+}
+
+impl CargoFetchSource {
+    pub fn new(origin: String, checksum: Option<String>) -> Self {
+        CargoFetchSource { origin, checksum }
+    }
+}
+
+impl Transformation for CargoFetchSource {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        if !self.origin.ends_with(".crate") {
+            ctx.sh.run_shell(format!("cp -r {}/. .", self.origin))?;
+            ctx.sh.run_shell("rm -f .cargo-checksum.json .cargo-ok".to_string())?;
+            return Ok(Some("fetched from vendored sources".to_string()));
+        }
+
+        if let Some(checksum) = &self.checksum {
+            let actual_checksum: String = ctx.sh.run_shell(format!("sha256sum {}", self.origin))?
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+
+            if &actual_checksum != checksum {
+                bail!(
+                    "checksum mismatch for {}, expected {} but got {}",
+                    self.origin,
+                    checksum,
+                    actual_checksum,
+                );
+            }
+        }
+
+        ctx.sh.run_shell(format!("tar -xzf {} --strip-components=1", self.origin))?;
+        Ok(Some("fetched from Cargo registry cache".to_string()))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "fetch cargo source code".to_string()
+    }
+}
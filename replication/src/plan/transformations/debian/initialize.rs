@@ -0,0 +1,138 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use anyhow::Result;
+
+use source_wand_common::{
+    identity::semantic_version::SemanticVersion,
+    project_manipulator::project_manipulator::ProjectManipulator,
+    utils::write_text_file::write_text_file
+};
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+use crate::model::{
+    debian::{
+        changelog::DebianChangelog,
+        control::DebianControl,
+        copyright::DebianCopyright,
+        naming::debian_version
+    },
+    dependency::Dependency
+};
+
+const RULES: &str = "#!/usr/bin/make -f\n\n%:\n\tdh $@ --builddirectory=_build --buildsystem=golang\n";
+
+#[derive(Debug, Clone)]
+pub struct DebianInitialize {
+    pub module: String,
+    pub version: String,
+    pub maintainer: String,
+    /// Dependencies of the package, named after their Go module path.
+    pub dependencies: Vec<Dependency>,
+
+    pub is_library: bool,
+}
+
+impl DebianInitialize {
+    pub fn new(
+        module: String,
+        version: String,
+        maintainer: String,
+        dependencies: Vec<Dependency>,
+        is_library: bool,
+    ) -> Self {
+        DebianInitialize {
+            module,
+            version,
+            maintainer,
+            dependencies,
+            is_library,
+        }
+    }
+
+    fn debian_version(&self) -> String {
+        debian_version(&SemanticVersion::new(&self.version))
+    }
+
+    fn copyright(&self, ctx: &Context) -> DebianCopyright {
+        let license_text: Option<String> = ctx.sh.run_shell(
+            "ls | grep -i -E '^(license|licence|copying)' | head -n 1".to_string()
+        )
+            .ok()
+            .map(|file| file.trim().to_string())
+            .filter(|file| !file.is_empty())
+            .and_then(|file| read_to_string(ctx.sh.project_root.join(file)).ok());
+
+        DebianCopyright::new(self.module.clone(), license_text)
+    }
+
+    /// Every file of the packaging but the changelog, whose date changes on every run.
+    fn files(&self, copyright: &DebianCopyright) -> Vec<(&'static str, String)> {
+        let control: DebianControl = DebianControl::new(
+            self.module.clone(),
+            self.maintainer.clone(),
+            self.dependencies.clone(),
+            self.is_library,
+        );
+
+        vec![
+            ("control", control.render()),
+            ("copyright", copyright.render()),
+            ("rules", RULES.to_string()),
+            ("source/format", "3.0 (quilt)\n".to_string()),
+        ]
+    }
+}
+
+impl Transformation for DebianInitialize {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let debian: PathBuf = ctx.sh.project_root.join("debian");
+        let copyright: DebianCopyright = self.copyright(&ctx);
+
+        let changelog: DebianChangelog = DebianChangelog::new(
+            self.module.clone(),
+            self.debian_version(),
+            self.maintainer.clone(),
+            ctx.sh.run_shell("date -R".to_string())?.trim().to_string(),
+        );
+
+        for (file, content) in self.files(&copyright) {
+            write_text_file(&content, debian.join(file).to_str().unwrap())?;
+        }
+        write_text_file(&changelog.render(), debian.join("changelog").to_str().unwrap())?;
+
+        ctx.sh.run_shell("chmod +x debian/rules".to_string())?;
+
+        Ok(Some(format!("packaged version {} ({})", self.debian_version(), copyright.license)))
+    }
+
+    fn should_skip(&self, ctx: &Context) -> Option<String> {
+        let debian: PathBuf = ctx.sh.project_root.join("debian");
+
+        let changelog: String = ctx.sh.run_shell(
+            "head -n 1 debian/changelog".to_string()
+        ).unwrap_or_default();
+
+        if !changelog.contains(format!("({})", self.debian_version()).as_str()) {
+            return None;
+        }
+
+        // The dependencies, the architecture or the license may change without a new version.
+        let is_up_to_date: bool = self.files(&self.copyright(ctx))
+            .iter()
+            .all(|(file, content)| read_to_string(debian.join(file)).ok().as_ref() == Some(content));
+
+        if is_up_to_date {
+            Some(format!("debian packaging is up to date for version {}", self.debian_version()))
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "initialize debian packaging".to_string()
+    }
+}
@@ -0,0 +1,40 @@
+/// Builds the `git commit` arguments of a commit message ending with the given trailers.
+pub fn commit_message_arguments(text: &str, trailers: &[(String, String)]) -> String {
+    let mut arguments: String = format!("-m '{}'", escape(text));
+
+    if !trailers.is_empty() {
+        let trailers: String = trailers
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        arguments.push_str(format!(" -m '{}'", escape(&trailers)).as_str());
+    }
+
+    arguments
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\'', "'\\''")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_trailers_as_a_paragraph() {
+        let trailers: Vec<(String, String)> = vec![
+            ("Upstream-Version".to_string(), "1.2.3".to_string()),
+            ("Plan-Hash".to_string(), "abc".to_string()),
+        ];
+
+        assert_eq!(commit_message_arguments("Initialize sourcecraft", &[]), "-m 'Initialize sourcecraft'");
+        assert_eq!(commit_message_arguments("Apply patch fix's", &[]), "-m 'Apply patch fix'\\''s'");
+        assert_eq!(
+            commit_message_arguments("Replicate source code", &trailers),
+            "-m 'Replicate source code' -m 'Upstream-Version: 1.2.3\nPlan-Hash: abc'"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct GitBundle {
+    bundle_file: PathBuf,
+    reference: String,
+    tag: Option<String>,
+}
+
+impl GitBundle {
+    pub fn new(bundle_file: PathBuf, reference: String, tag: Option<String>) -> Self {
+        GitBundle { bundle_file, reference, tag }
+    }
+}
+
+impl Transformation for GitBundle {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let bundle_file: &str = self.bundle_file.to_str().unwrap_or_default();
+
+        ctx.sh.run_shell(format!("mkdir -p $(dirname {})", bundle_file))?;
+
+        let mut references: Vec<String> = vec![self.reference.clone()];
+
+        if let Some(tag) = &self.tag {
+            // The tag may have been pushed by a previous replication without being in this workdesk.
+            ctx.sh.run_shell(format!("git fetch -q origin +refs/tags/{tag}:refs/tags/{tag}", tag = tag))?;
+            references.push(format!("refs/tags/{}", tag));
+        }
+
+        ctx.sh.run_shell(format!("git bundle create {} {}", bundle_file, references.join(" ")))?;
+
+        Ok(Some(format!("bundled to {}", bundle_file)))
+    }
+
+    fn should_skip(&self, ctx: &Context) -> Option<String> {
+        let bundle_file: &str = self.bundle_file.to_str().unwrap_or_default();
+
+        let up_to_date: Result<String> = ctx.sh.run_shell(
+            format!(
+                "git bundle list-heads {} refs/heads/{} | grep -q \"^$(git rev-parse HEAD) \"",
+                bundle_file,
+                self.reference,
+            )
+        );
+
+        if up_to_date.is_ok() {
+            Some(format!("{} is up to date", bundle_file))
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "bundle git repository".to_string()
+    }
+}
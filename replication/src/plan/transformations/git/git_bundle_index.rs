@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+
+use source_wand_common::{
+    project_manipulator::project_manipulator::ProjectManipulator,
+    utils::{
+        read_yaml_file::read_yaml_file,
+        write_yaml_file::write_yaml_file
+    }
+};
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleIndexEntry {
+    pub name: String,
+    pub version: String,
+    pub reference: String,
+    pub bundle: String,
+    pub commit: Option<String>,
+}
+
+/// Lists the bundles of a bundle destination, so that they can be unbundled on the other side.
+#[derive(Debug, Clone)]
+pub struct GitBundleIndex {
+    index_file: PathBuf,
+    entries: Vec<BundleIndexEntry>,
+}
+
+impl GitBundleIndex {
+    pub fn new(index_file: PathBuf, entries: Vec<BundleIndexEntry>) -> Self {
+        GitBundleIndex { index_file, entries }
+    }
+}
+
+impl Transformation for GitBundleIndex {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let root: PathBuf = self.index_file.parent().map(|root| root.to_path_buf()).unwrap_or_default();
+
+        let mut entries: Vec<BundleIndexEntry> = self.entries
+            .iter()
+            .map(|entry| {
+                let commit: Option<String> = ctx.sh.run_shell(
+                    format!(
+                        "git bundle list-heads {} refs/heads/{}",
+                        root.join(&entry.bundle).to_str().unwrap_or_default(),
+                        entry.reference,
+                    )
+                )
+                    .ok()
+                    .and_then(|heads| heads.split_whitespace().next().map(|commit| commit.to_string()));
+
+                BundleIndexEntry { commit, ..entry.clone() }
+            })
+            .collect();
+
+        // Bundles that were not replicated this time (i.e. during an upgrade) stay indexed.
+        if self.index_file.exists() {
+            let existing: Vec<BundleIndexEntry> = read_yaml_file(self.index_file.to_str().unwrap_or_default())?;
+
+            entries.extend(
+                existing.into_iter().filter(|existing| {
+                    !self.entries.iter().any(|entry| entry.bundle == existing.bundle)
+                })
+            );
+        }
+
+        write_yaml_file(&entries, self.index_file.to_str().unwrap_or_default())?;
+
+        Ok(Some(format!("indexed {} bundles", entries.len())))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "index git bundles".to_string()
+    }
+}
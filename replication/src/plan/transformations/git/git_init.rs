@@ -7,7 +7,7 @@ use source_wand_concurrent_executor::{
     transformation::Transformation
 };
 
-use crate::model::replication_config::GitIdentity;
+use crate::model::replication_config::{GitIdentity, SigningFormat};
 
 #[derive(Debug, Clone)]
 pub struct GitInit {
@@ -45,6 +45,11 @@ impl Transformation for GitInit {
             ctx.sh.run_shell(format!("git checkout {}", self.reference))?;
             ctx.sh.run_shell("git pull".to_string())?;
         }
+        else if ctx.sh.run_shell("test -d .git".to_string()).is_ok() {
+            ctx.sh.run_shell("git remote rename origin upstream".to_string())?;
+            ctx.sh.run_shell(format!("git remote add origin {}", self.repository_url))?;
+            ctx.sh.run_shell(format!("git checkout -b {}", self.reference))?;
+        }
         else {
             ctx.sh.run_shell("git init".to_string())?;
             ctx.sh.run_shell(format!("git remote add origin {}", self.repository_url))?;
@@ -54,6 +59,15 @@ impl Transformation for GitInit {
         if let Some(git_identity) = &self.git_identity {
             ctx.sh.run_shell(format!("git config --local user.name {}", git_identity.username))?;
             ctx.sh.run_shell(format!("git config --local user.email {}", git_identity.email))?;
+
+            if let Some(signing_key) = &git_identity.signing_key {
+                let signing_format: SigningFormat = git_identity.signing_format.clone().unwrap_or_default();
+
+                ctx.sh.run_shell(format!("git config --local gpg.format {}", signing_format.git_format()))?;
+                ctx.sh.run_shell(format!("git config --local user.signingkey '{}'", signing_key))?;
+                ctx.sh.run_shell("git config --local commit.gpgsign true".to_string())?;
+                ctx.sh.run_shell("git config --local tag.gpgsign true".to_string())?;
+            }
         }
 
         Ok(None)
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+/// Creates the local repository a package is pushed to, so that it can be used as a git remote.
+#[derive(Debug, Clone)]
+pub struct GitInitLocal {
+    repository: PathBuf,
+    reference: String,
+    bare: bool,
+}
+
+impl GitInitLocal {
+    pub fn new(repository: PathBuf, reference: String, bare: bool) -> Self {
+        GitInitLocal { repository, reference, bare }
+    }
+}
+
+impl Transformation for GitInitLocal {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let repository: &str = self.repository.to_str().unwrap_or_default();
+
+        ctx.sh.run_shell(format!("mkdir -p {}", repository))?;
+
+        if self.bare {
+            ctx.sh.run_shell(format!("git init --bare {}", repository))?;
+        }
+        else {
+            ctx.sh.run_shell(format!("git init {}", repository))?;
+            ctx.sh.run_shell(format!("git -C {} config receive.denyCurrentBranch updateInstead", repository))?;
+        }
+
+        ctx.sh.run_shell(format!("git -C {} symbolic-ref HEAD refs/heads/{}", repository, self.reference))?;
+
+        Ok(Some(format!("created {}", repository)))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        let exists: bool = self.repository.join("HEAD").exists() || self.repository.join(".git").exists();
+
+        if exists {
+            Some(format!("{} already exists", self.repository.to_str().unwrap_or_default()))
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "initialize local repository".to_string()
+    }
+}
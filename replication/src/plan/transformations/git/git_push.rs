@@ -7,28 +7,42 @@ use source_wand_concurrent_executor::{
     transformation::Transformation
 };
 
+use crate::plan::transformations::git::commit_message::commit_message_arguments;
+
 #[derive(Debug, Clone)]
 pub struct GitPush {
     reference: String,
     commit_text: String,
+    trailers: Vec<(String, String)>,
 }
 
 impl GitPush {
     pub fn new(
         reference: String,
         commit_text: String,
+        trailers: Vec<(String, String)>,
     ) -> Self {
-        GitPush { reference, commit_text }
+        GitPush { reference, commit_text, trailers }
     }
 }
 
 impl Transformation for GitPush {
     fn apply(&self, ctx: Context) -> Result<Option<String>> {
         ctx.sh.run_shell("git add .".to_string())?;
-        ctx.sh.run_shell(format!("git commit -m '{}'", self.commit_text))?;
+
+        let has_changes: bool = ctx.sh.run_shell("git diff --cached --quiet".to_string()).is_err();
+        if has_changes {
+            ctx.sh.run_shell(format!("git commit {}", commit_message_arguments(&self.commit_text, &self.trailers)))?;
+        }
+
         ctx.sh.run_shell(format!("git push -u origin {}", self.reference))?;
 
-        Ok(Some(format!("commit \"{}\"", self.commit_text)))
+        if has_changes {
+            Ok(Some(format!("commit \"{}\"", self.commit_text)))
+        }
+        else {
+            Ok(Some("push fetched history".to_string()))
+        }
     }
 
     fn should_skip(&self, ctx: &Context) -> Option<String> {
@@ -40,7 +54,11 @@ impl Transformation for GitPush {
             "git diff --quiet && git diff --cached --quiet && [ -z \"$(git ls-files --others --exclude-standard)\" ]".to_string()
         );
 
-        if clean_tree.is_ok() {
+        let pushed_history: Result<String> = ctx.sh.run_shell(
+            "! git rev-parse --verify HEAD > /dev/null 2>&1 || (git rev-parse @{u} > /dev/null 2>&1 && [ -z \"$(git rev-list @{u}..HEAD)\" ])".to_string()
+        );
+
+        if clean_tree.is_ok() && pushed_history.is_ok() {
             Some("there is nothing to push".to_string())
         }
         else {
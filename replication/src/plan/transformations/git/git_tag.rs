@@ -0,0 +1,82 @@
+use anyhow::Result;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct GitTag {
+    tag: String,
+    upstream_version: String,
+    upstream_url: String,
+    source_commit: Option<String>,
+}
+
+impl GitTag {
+    pub fn new(
+        tag: String,
+        upstream_version: String,
+        upstream_url: String,
+        source_commit: Option<String>,
+    ) -> Self {
+        GitTag { tag, upstream_version, upstream_url, source_commit }
+    }
+
+    fn message(&self) -> Vec<String> {
+        let mut message: Vec<String> = vec![
+            format!("Upstream version: {}", self.upstream_version),
+            format!("Upstream URL: {}", self.upstream_url),
+        ];
+
+        if let Some(source_commit) = &self.source_commit {
+            message.push(format!("Source commit: {}", source_commit));
+        }
+
+        message
+    }
+}
+
+impl Transformation for GitTag {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let message: String = self.message()
+            .iter()
+            .map(|paragraph| format!("-m '{}'", paragraph))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        // The workdesk may already have a tag of the same name (i.e. from the upstream history),
+        // which points to the upstream commit instead of the replicated one.
+        ctx.sh.run_shell(
+            format!(
+                "git tag -f -a {} -m 'Replicate version {}' {} HEAD",
+                self.tag,
+                self.upstream_version,
+                message,
+            )
+        )?;
+
+        ctx.sh.run_shell(format!("git push origin refs/tags/{}", self.tag))?;
+
+        Ok(Some(format!("tag {}", self.tag)))
+    }
+
+    fn should_skip(&self, ctx: &Context) -> Option<String> {
+        let tag_exists: Result<String> = ctx.sh.run_shell(
+            format!("git ls-remote --exit-code --tags origin refs/tags/{}", self.tag)
+        );
+
+        if tag_exists.is_ok() {
+            Some(format!("tag {} already exists", self.tag))
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "tag git repository".to_string()
+    }
+}
@@ -1,2 +1,8 @@
+pub mod commit_message;
+
 pub mod git_init;
+pub mod git_init_local;
 pub mod git_push;
+pub mod git_tag;
+pub mod git_bundle;
+pub mod git_bundle_index;
@@ -20,8 +20,10 @@ impl GolangFetchSource {
 
 impl Transformation for GolangFetchSource {
     fn apply(&self, ctx: Context) -> Result<Option<String>> {
-        ctx.sh.run_shell(format!("cp -r {}/* .", self.origin))?;
-        Ok(None)
+        // Copy the hidden files too, a module is only verified against go.sum when it is complete.
+        // The history of a local working tree is left out, as it is for a commit of a local repository.
+        ctx.sh.run_shell(format!("tar -C \"{}\" --exclude=./.git -c . | tar -x && chmod -R u+w .", self.origin))?;
+        Ok(Some("fetched from Go proxy".to_string()))
     }
 
     fn should_skip(&self, _: &Context) -> Option<String> {
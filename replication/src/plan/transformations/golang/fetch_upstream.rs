@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+use source_wand_dependency_analysis::dependency_tree_generators::go_dependency_tree_generator::{
+    fetch_checkout,
+    resolve_repository_url
+};
+
+#[derive(Debug, Clone)]
+pub struct GolangFetchUpstream {
+    pub module: String,
+    pub version: String,
+    pub commit: Option<String>,
+}
+
+impl GolangFetchUpstream {
+    pub fn new(module: String, version: String, commit: Option<String>) -> Self {
+        GolangFetchUpstream { module, version, commit }
+    }
+
+    fn find_checkout(&self, repository: &String) -> Result<(String, Option<String>)> {
+        if let Some(commit) = &self.commit {
+            return Ok((commit.clone(), None));
+        }
+
+        let (checkout, subdirectory) = fetch_checkout(&self.module, &self.version, repository)?;
+
+        if let Some(checkout) = checkout {
+            return Ok((checkout, subdirectory));
+        }
+
+        let pseudo_version: Regex = Regex::new(r"\d{14}-([a-f0-9]{12})(\+incompatible)?$").unwrap();
+
+        match pseudo_version.captures(&self.version) {
+            Some(captures) => Ok((captures[1].to_string(), None)),
+            None => Err(anyhow!("No tag of {} matches version {}", repository, self.version)),
+        }
+    }
+}
+
+impl Transformation for GolangFetchUpstream {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let repository: String = resolve_repository_url(&self.module);
+        let (checkout, subdirectory) = self.find_checkout(&repository)?;
+
+        ctx.sh.run_shell(format!("git clone {} .", repository))?;
+        ctx.sh.run_shell(format!("git checkout {}", checkout))?;
+
+        if let Some(subdirectory) = subdirectory {
+            ctx.sh.run_shell(format!("git subtree split --prefix={} -b source-wand-subtree", subdirectory))?;
+            ctx.sh.run_shell("git checkout source-wand-subtree".to_string())?;
+        }
+
+        Ok(Some(format!("fetched from {} at {}", repository, checkout)))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "fetch go upstream history".to_string()
+    }
+}
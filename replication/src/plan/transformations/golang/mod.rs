@@ -1 +1,3 @@
 pub mod fetch_source;
+pub mod fetch_upstream;
+pub mod publish_proxy;
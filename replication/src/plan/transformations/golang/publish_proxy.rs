@@ -0,0 +1,112 @@
+use std::{
+    fs::{canonicalize, create_dir_all},
+    path::PathBuf
+};
+
+use anyhow::Result;
+
+use source_wand_common::{
+    project_manipulator::project_manipulator::ProjectManipulator,
+    utils::write_text_file::write_text_file
+};
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct GolangPublishProxy {
+    pub module: String,
+    pub version: String,
+    pub goproxy: String,
+}
+
+impl GolangPublishProxy {
+    pub fn new(module: String, version: String, goproxy: String) -> Self {
+        GolangPublishProxy { module, version, goproxy }
+    }
+
+    fn versions_directory(&self) -> PathBuf {
+        PathBuf::from(&self.goproxy)
+            .join(escape_module_path(&self.module))
+            .join("@v")
+    }
+}
+
+impl Transformation for GolangPublishProxy {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        create_dir_all(self.versions_directory())?;
+
+        let versions_directory: PathBuf = canonicalize(self.versions_directory())?;
+        let versions_directory: &str = versions_directory.to_str().unwrap_or_default();
+
+        write_text_file(
+            &format!("{{\"Version\":\"{}\"}}\n", self.version),
+            format!("{}/{}.info", versions_directory, self.version).as_str(),
+        )?;
+
+        if ctx.sh.run_shell("ls go.mod".to_string()).is_ok() {
+            ctx.sh.run_shell(format!("cp go.mod \"{}/{}.mod\"", versions_directory, self.version))?;
+        }
+        else {
+            write_text_file(
+                &format!("module {}\n", self.module),
+                format!("{}/{}.mod", versions_directory, self.version).as_str(),
+            )?;
+        }
+
+        ctx.sh.run_shell(
+            format!(
+                "staging=$(mktemp -d) && \
+                 mkdir -p \"$staging/{module}@{version}\" && \
+                 cp -r ./. \"$staging/{module}@{version}/\" && \
+                 rm -rf \"$staging/{module}@{version}/.git\" && \
+                 (cd \"$staging\" && rm -f \"{directory}/{version}.zip\" && zip -qrX \"{directory}/{version}.zip\" \"{module}@{version}\") && \
+                 rm -rf \"$staging\"",
+                module = self.module,
+                version = self.version,
+                directory = versions_directory,
+            )
+        )?;
+
+        ctx.sh.run_shell(
+            format!(
+                "grep -qxF '{version}' \"{directory}/list\" 2>/dev/null || echo '{version}' >> \"{directory}/list\"",
+                version = self.version,
+                directory = versions_directory,
+            )
+        )?;
+
+        Ok(Some(format!("published to {}", versions_directory)))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        let archive: PathBuf = self.versions_directory().join(format!("{}.zip", self.version));
+
+        if archive.exists() {
+            Some("version is already published".to_string())
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "publish to go proxy".to_string()
+    }
+}
+
+pub fn escape_module_path(module: &str) -> String {
+    module
+        .chars()
+        .map(|character| {
+            if character.is_ascii_uppercase() {
+                format!("!{}", character.to_ascii_lowercase())
+            }
+            else {
+                character.to_string()
+            }
+        })
+        .collect()
+}
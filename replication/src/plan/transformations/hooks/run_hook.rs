@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct RunHook {
+    pub hook: String,
+    pub command: String,
+    pub environment: Vec<(String, String)>,
+}
+
+impl RunHook {
+    pub fn new(
+        hook: String,
+        command: String,
+        environment: Vec<(String, String)>,
+    ) -> Self {
+        RunHook { hook, command, environment }
+    }
+}
+
+impl Transformation for RunHook {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        let exports: String = self.environment
+            .iter()
+            .map(|(key, value)| format!("export {}='{}'\n", key, value.replace('\'', "'\\''")))
+            .collect();
+
+        ctx.sh.run_shell(format!("{}{}", exports, self.command))?;
+
+        Ok(Some(self.command.clone()))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        format!("run {} hook", self.hook)
+    }
+}
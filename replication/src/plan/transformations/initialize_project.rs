@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 
 use source_wand_concurrent_executor::{
@@ -5,21 +7,18 @@ use source_wand_concurrent_executor::{
     transformation::Transformation,
 };
 
-use crate::plan::transformations::{
-    git::git_init::GitInit,
-    golang::fetch_source::GolangFetchSource,
-};
+use crate::plan::transformations::git::git_init::GitInit;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct InitializeProject {
     git_init: GitInit,
-    fetch_source: GolangFetchSource,
+    fetch_source: Arc<dyn Transformation>,
 }
 
 impl InitializeProject {
     pub fn new(
         git_init: GitInit,
-        fetch_source: GolangFetchSource,
+        fetch_source: Arc<dyn Transformation>,
     ) -> Self {
         InitializeProject { git_init, fetch_source }
     }
@@ -32,9 +31,9 @@ impl Transformation for InitializeProject {
             Ok(Some("fetched back from mirror".to_string()))
         }
         else {
-            self.fetch_source.apply(ctx.clone())?;
+            let message: Option<String> = self.fetch_source.apply(ctx.clone())?;
             self.git_init.apply(ctx.clone())?;
-            Ok(Some("fetched from Go proxy".to_string()))
+            Ok(message)
         }
     }
 
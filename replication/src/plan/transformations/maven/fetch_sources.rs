@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct MavenFetchSources {
+    pub sources_jar: String,
+    pub pom: Option<String>,
+}
+
+impl MavenFetchSources {
+    pub fn new(sources_jar: String, pom: Option<String>) -> Self {
+        MavenFetchSources { sources_jar, pom }
+    }
+}
+
+impl Transformation for MavenFetchSources {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        if Path::new(&self.sources_jar).is_dir() {
+            ctx.sh.run_shell(format!("cp -r {}/. .", self.sources_jar))?;
+            return Ok(Some("fetched from local project".to_string()));
+        }
+
+        ctx.sh.run_shell(
+            format!(
+                "mkdir -p src/main/java && unzip -q -o {} -d src/main/java -x 'META-INF/*'",
+                self.sources_jar,
+            )
+        )?;
+
+        if let Some(pom) = &self.pom {
+            ctx.sh.run_shell(format!("cp {} pom.xml", pom))?;
+        }
+
+        Ok(Some("unpacked sources jar".to_string()))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "fetch maven sources".to_string()
+    }
+}
@@ -1,5 +1,11 @@
 pub mod golang;
+pub mod cargo;
+pub mod python;
+pub mod maven;
 pub mod git;
+pub mod hooks;
+pub mod patches;
 pub mod sourcecraft;
+pub mod debian;
 
 pub mod initialize_project;
@@ -0,0 +1,108 @@
+use std::{
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf}
+};
+
+use anyhow::{bail, Result};
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+use crate::plan::transformations::git::commit_message::commit_message_arguments;
+
+#[derive(Debug, Clone)]
+pub struct ApplyPatches {
+    pub directory: PathBuf,
+    pub base_commit_text: String,
+    pub trailers: Vec<(String, String)>,
+}
+
+impl ApplyPatches {
+    pub fn new(directory: PathBuf, base_commit_text: String, trailers: Vec<(String, String)>) -> Self {
+        ApplyPatches { directory, base_commit_text, trailers }
+    }
+
+    fn series(&self) -> Result<Vec<(String, String)>> {
+        patch_series(&self.directory)
+    }
+}
+
+/// The patches of a directory along with their `git apply` options, in the order they are applied.
+pub fn patch_series(directory: &Path) -> Result<Vec<(String, String)>> {
+    let series_file: PathBuf = directory.join("series");
+
+    if series_file.exists() {
+        let series: Vec<(String, String)> = read_to_string(series_file)?
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (patch, options) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+                (patch.to_string(), options.trim().to_string())
+            })
+            .collect();
+
+        return Ok(series);
+    }
+
+    let mut patches: Vec<String> = read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".patch") || name.ends_with(".diff"))
+        .collect();
+
+    patches.sort();
+
+    Ok(patches.into_iter().map(|patch| (patch, String::new())).collect())
+}
+
+impl Transformation for ApplyPatches {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        ctx.sh.run_shell("git add .".to_string())?;
+        if ctx.sh.run_shell("git diff --cached --quiet".to_string()).is_err() {
+            ctx.sh.run_shell(format!("git commit {}", commit_message_arguments(&self.base_commit_text, &self.trailers)))?;
+        }
+
+        let mut applied: Vec<String> = Vec::new();
+
+        for (patch, options) in self.series()? {
+            let patch_file: String = self.directory.join(&patch).to_str().unwrap_or_default().to_string();
+
+            let already_applied: bool = ctx.sh.run_shell(
+                format!("git apply --reverse --check {} {}", options, patch_file)
+            ).is_ok();
+
+            if already_applied {
+                continue;
+            }
+
+            if let Err(e) = ctx.sh.run_shell(format!("git apply --index {} {}", options, patch_file)) {
+                bail!("patch {} does not apply: {}", patch, e);
+            }
+
+            ctx.sh.run_shell(
+                format!("git commit {}", commit_message_arguments(&format!("Apply patch {}", patch), &self.trailers))
+            )?;
+            applied.push(patch);
+        }
+
+        if applied.is_empty() {
+            Ok(Some("patches were already applied".to_string()))
+        }
+        else {
+            Ok(Some(format!("applied {}", applied.join(", "))))
+        }
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "apply patches".to_string()
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use source_wand_common::project_manipulator::project_manipulator::ProjectManipulator;
+
+use source_wand_concurrent_executor::{
+    context::Context,
+    transformation::Transformation
+};
+
+#[derive(Debug, Clone)]
+pub struct PythonFetchSdist {
+    pub sdist: String,
+    pub sha256: Option<String>,
+}
+
+impl PythonFetchSdist {
+    pub fn new(sdist: String, sha256: Option<String>) -> Self {
+        PythonFetchSdist { sdist, sha256 }
+    }
+}
+
+impl Transformation for PythonFetchSdist {
+    fn apply(&self, ctx: Context) -> Result<Option<String>> {
+        if Path::new(&self.sdist).is_dir() {
+            ctx.sh.run_shell(format!("cp -r {}/. .", self.sdist))?;
+            return Ok(Some("fetched from local project".to_string()));
+        }
+
+        if let Some(sha256) = &self.sha256 {
+            let actual_sha256: String = ctx.sh.run_shell(format!("sha256sum {}", self.sdist))?
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+
+            if &actual_sha256 != sha256 {
+                bail!(
+                    "checksum mismatch for {}, expected {} but got {}",
+                    self.sdist,
+                    sha256,
+                    actual_sha256,
+                );
+            }
+        }
+
+        if self.sdist.ends_with(".zip") {
+            ctx.sh.run_shell(
+                format!(
+                    "extract=$(mktemp -d) && \
+                     unzip -q {} -d \"$extract\" && \
+                     cp -r \"$extract\"/*/. . && \
+                     rm -rf \"$extract\"",
+                    self.sdist,
+                )
+            )?;
+        }
+        else {
+            ctx.sh.run_shell(format!("tar -xf {} --strip-components=1", self.sdist))?;
+        }
+
+        Ok(Some("unpacked sdist".to_string()))
+    }
+
+    fn should_skip(&self, _: &Context) -> Option<String> {
+        None
+    }
+
+    fn get_name(&self) -> String {
+        "fetch python sdist".to_string()
+    }
+}
@@ -1,10 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::{BTreeMap, HashMap}, path::Path};
 
 use anyhow::Result;
 
 use source_wand_common::{
     project_manipulator::project_manipulator::ProjectManipulator,
-    utils::write_yaml_file::write_yaml_file
+    utils::{
+        read_yaml_file::read_yaml_file,
+        write_yaml_file::write_yaml_file
+    }
 };
 use source_wand_concurrent_executor::{
     context::Context,
@@ -14,59 +17,89 @@ use source_wand_concurrent_executor::{
 use crate::{
     model::{
         dependency::Dependency,
+        ecosystem::Ecosystem,
+        package::Package,
         sourcecraft::{
             part::Part,
+            platform::Platform,
             sourcecraft_metadata::SourcecraftMetadata
-        }
+        },
+        target::Target
     },
 };
 
 #[derive(Debug, Clone)]
 pub struct SourcecraftInitialize {
     pub name: String,
+    pub ecosystem: Ecosystem,
     pub version: String,
     pub base: String,
+    pub risk: String,
     pub platforms: Vec<String>,
     pub dependencies: Vec<Dependency>,
 
     pub is_library: bool,
+
+    pub build_snaps: Vec<String>,
+    pub build_environment: Vec<HashMap<String, String>>,
 }
 
 impl SourcecraftInitialize {
     pub fn new(
         name: String,
         version: String,
-        base: String,
-        platforms: Vec<String>,
-        dependencies: Vec<Dependency>,
-        is_library: bool,
+        target: &Target,
+        package: &Package,
     ) -> Self {
         SourcecraftInitialize {
             name,
+            ecosystem: package.origin.ecosystem(),
             version,
-            base,
-            platforms,
-            dependencies,
-            is_library,
+            base: target.base(),
+            risk: target.risk.clone(),
+            platforms: target.platforms.clone(),
+            dependencies: package.dependencies.clone(),
+            is_library: package.is_library,
+            build_snaps: package.build_snaps.clone(),
+            build_environment: package.build_environment.clone(),
         }
     }
 }
 
 impl Transformation for SourcecraftInitialize {
     fn apply(&self, ctx: Context) -> Result<Option<String>> {
-        let sourcecraft_metadata: SourcecraftMetadata = SourcecraftMetadata::from_args(&self);
-        write_yaml_file(
-            &sourcecraft_metadata,
-            format!("{}/sourcecraft.yaml", ctx.sh.project_root.to_str().unwrap()).as_str(),
-        )?;
-        Ok(None)
+        let file: String = format!("{}/sourcecraft.yaml", ctx.sh.project_root.to_str().unwrap());
+
+        let existing: Option<SourcecraftMetadata> = read_existing_metadata(&file)?;
+        let sourcecraft_metadata: SourcecraftMetadata = match &existing {
+            Some(existing) => SourcecraftMetadata::from_args(self).merge(existing),
+            None => SourcecraftMetadata::from_args(self),
+        };
+
+        write_yaml_file(&sourcecraft_metadata, file.as_str())?;
+
+        if existing.is_none() {
+            return Ok(Some("created sourcecraft.yaml".to_string()));
+        }
+
+        let diff: String = ctx.sh.run_shell(
+            "git diff --no-color -- sourcecraft.yaml".to_string()
+        ).unwrap_or_default();
+
+        Ok(Some(format!("updated sourcecraft.yaml\n{}", diff.trim_end())))
     }
 
     fn should_skip(&self, ctx: &Context) -> Option<String> {
-        if ctx.sh.run_shell(
-            "ls | grep \"^sourcecraft.yaml$\"".to_string()
-        ).unwrap_or_default().trim() == "sourcecraft.yaml" {
-            Some("sourcecraft.yaml already exists".to_string())
+        let file: String = format!("{}/sourcecraft.yaml", ctx.sh.project_root.to_str().unwrap());
+
+        let existing: SourcecraftMetadata = read_existing_metadata(&file).ok()??;
+        let merged: SourcecraftMetadata = SourcecraftMetadata::from_args(self).merge(&existing);
+
+        let existing_yaml: String = serde_yaml::to_string(&existing).ok()?;
+        let merged_yaml: String = serde_yaml::to_string(&merged).ok()?;
+
+        if existing_yaml == merged_yaml {
+            Some("sourcecraft.yaml is up to date".to_string())
         }
         else {
             None
@@ -78,61 +111,157 @@ impl Transformation for SourcecraftInitialize {
     }
 }
 
+fn read_existing_metadata(file: &String) -> Result<Option<SourcecraftMetadata>> {
+    if !Path::new(file).exists() {
+        return Ok(None);
+    }
+
+    Ok(Some(read_yaml_file(file)?))
+}
+
 
 impl SourcecraftMetadata {
     pub fn from_args(args: &SourcecraftInitialize) -> Self {
-        let mut parts: HashMap<String, Part> = HashMap::new();
+        let mut parts: BTreeMap<String, Part> = BTreeMap::new();
+
+        for dependency in &args.dependencies {
+            let part: Part = match args.ecosystem {
+                Ecosystem::Go => Part::with_go_use_plugin(
+                    dependency.name.clone(),
+                    dependency.version.clone(),
+                    args.risk.clone(),
+                    &dependency.platforms,
+                ),
+                Ecosystem::Rust => Part::with_cargo_use_plugin(
+                    dependency.name.clone(),
+                    dependency.version.clone(),
+                    args.risk.clone(),
+                    &dependency.platforms,
+                ),
+                Ecosystem::Java => Part::with_maven_use_plugin(
+                    dependency.name.clone(),
+                    dependency.version.clone(),
+                    args.risk.clone(),
+                    &dependency.platforms,
+                ),
+                Ecosystem::Python => Part::with_python_dependency_plugin(
+                    dependency.name.clone(),
+                    dependency.version.clone(),
+                    args.risk.clone(),
+                    &dependency.platforms,
+                ),
+            };
+
+            parts.insert(dependency.name.clone(), part);
+        }
+
+        let after: Vec<String> = args.dependencies
+            .iter()
+            .map(|dependency| dependency.name.clone())
+            .collect();
+
+        if args.ecosystem == Ecosystem::Python {
+            let mut build_environment: Vec<HashMap<String, String>> = vec![
+                HashMap::from([("PIP_NO_INDEX".to_string(), "true".to_string())]),
+            ];
+            build_environment.extend(args.build_environment.clone());
+
+            parts.insert(
+                args.name.clone(),
+                Part::with_python_plugin(
+                    ".".to_string(),
+                    args.build_snaps.clone(),
+                    build_environment,
+                    after,
+                )
+            );
+        }
+        else if args.is_library {
+            parts.insert(
+                args.name.clone(),
+                match args.ecosystem {
+                    Ecosystem::Rust => Part::with_local_cargo_use_plugin(".".to_string(), after),
+                    Ecosystem::Java => Part::with_local_maven_use_plugin(".".to_string(), after),
+                    _ => Part::with_local_go_use_plugin(".".to_string(), after),
+                },
+            );
+        }
+        else if args.ecosystem == Ecosystem::Rust {
+            let mut build_environment: Vec<HashMap<String, String>> = vec![
+                HashMap::from([("CARGO_NET_OFFLINE".to_string(), "true".to_string())]),
+            ];
+            build_environment.extend(args.build_environment.clone());
 
-        if args.is_library {
             parts.insert(
                 args.name.clone(),
-                Part::with_nil_plugin(),
+                Part::with_rust_plugin(
+                    ".".to_string(),
+                    args.build_snaps.clone(),
+                    build_environment,
+                    after,
+                )
+            );
+        }
+        else if args.ecosystem == Ecosystem::Java {
+            parts.insert(
+                args.name.clone(),
+                Part::with_maven_plugin(
+                    ".".to_string(),
+                    args.build_snaps.clone(),
+                    args.build_environment.clone(),
+                    after,
+                )
             );
         }
         else {
-            for dependency in &args.dependencies {
-                parts.insert(
-                    dependency.name.clone(),
-                    Part::with_go_use_plugin(
-                        dependency.name.clone(),
-                        dependency.version.clone()
-                    )
-                );
-            }
-    
+            let mut build_snaps: Vec<String> = vec!["go".to_string()];
+            build_snaps.extend(args.build_snaps.clone());
+
+            let mut build_environment: Vec<HashMap<String, String>> = [
+                ("GOFLAGS".to_string(), "-json".to_string()),
+                ("GOPROXY".to_string(), "False".to_string()),
+            ].iter()
+             .map(|(key, value)| {
+                let mut map: HashMap<String, String> = HashMap::new();
+                map.insert(key.clone(), value.clone());
+                map
+             }).collect();
+            build_environment.extend(args.build_environment.clone());
+
             parts.insert(
                 args.name.clone(),
                 Part::with_go_plugin(
                     ".".to_string(),
-                    vec!["go".to_string()],
-                    [
-                        ("GOFLAGS".to_string(), "-json".to_string()),
-                        ("GOPROXY".to_string(), "False".to_string()),
-                    ].iter()
-                     .map(|(key, value)| {
-                        let mut map: HashMap<String, String> = HashMap::new();
-                        map.insert(key.clone(), value.clone());
-                        map
-                     }).collect(),
-                    args.dependencies
-                        .iter()
-                        .map(|dependency| dependency.name.clone())
-                        .collect()
+                    build_snaps,
+                    build_environment,
+                    after,
                 )
             );
         }
 
+        let kind: &str = match (args.ecosystem, args.is_library) {
+            (Ecosystem::Go, true) => "Golang library",
+            (Ecosystem::Go, false) => "Golang program",
+            (Ecosystem::Rust, true) => "Rust library",
+            (Ecosystem::Rust, false) => "Rust program",
+            (Ecosystem::Python, true) => "Python library",
+            (Ecosystem::Python, false) => "Python program",
+            (Ecosystem::Java, true) => "Java library",
+            (Ecosystem::Java, false) => "Java program",
+        };
+
         SourcecraftMetadata {
             name: args.name.clone(),
             version: args.version.clone(),
             base: args.base.clone(),
-            summary: format!("{} version {} (Golang program)", args.name.clone(), args.version.clone()),
-            description: format!("{} version {} (Golang program), onboarded by source-wand", args.name.clone(), args.version.clone()),
+            summary: format!("{} version {} ({})", args.name.clone(), args.version.clone(), kind),
+            description: format!("{} version {} ({}), onboarded by source-wand", args.name.clone(), args.version.clone(), kind),
             platforms: args.platforms.clone()
                 .into_iter()
-                .map(|platform| (platform.clone(), ()))
+                .map(|platform| (platform.clone(), Some(Platform::native(&platform))))
                 .collect(),
-            parts
+            parts,
+            extra: BTreeMap::new(),
         }
     }
 }
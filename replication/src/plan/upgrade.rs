@@ -0,0 +1,279 @@
+use std::{collections::{BTreeMap, BTreeSet}, path::PathBuf};
+
+use source_wand_common::{
+    identity::semantic_version::SemanticVersion,
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+
+use crate::{
+    model::{
+        package::Package,
+        replication_plan::ReplicationPlan
+    },
+    verify::status::remote_for
+};
+
+#[derive(Debug, Clone)]
+pub struct VersionBump {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+    pub is_downgrade: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PlanDiff {
+    pub added: Vec<(String, String)>,
+    pub removed: Vec<(String, String)>,
+    pub bumped: Vec<VersionBump>,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.bumped.is_empty()
+    }
+}
+
+/// Lists the modules added, removed and version-bumped between two replication plans.
+pub fn diff_replication_plans(previous: &ReplicationPlan, next: &ReplicationPlan) -> PlanDiff {
+    let previous_versions: BTreeMap<String, BTreeSet<SemanticVersion>> = module_versions(previous);
+    let next_versions: BTreeMap<String, BTreeSet<SemanticVersion>> = module_versions(next);
+
+    let no_versions: BTreeSet<SemanticVersion> = BTreeSet::new();
+    let names: BTreeSet<&String> = previous_versions.keys().chain(next_versions.keys()).collect();
+
+    let mut diff: PlanDiff = PlanDiff::default();
+
+    for name in names {
+        let previous_versions: &BTreeSet<SemanticVersion> = previous_versions.get(name).unwrap_or(&no_versions);
+        let next_versions: &BTreeSet<SemanticVersion> = next_versions.get(name).unwrap_or(&no_versions);
+
+        let added: Vec<&SemanticVersion> = next_versions.difference(previous_versions).collect();
+        let removed: Vec<&SemanticVersion> = previous_versions.difference(next_versions).collect();
+
+        // A module can be planned in several versions, it is only bumped when one version replaces another.
+        if let ([to], [from]) = (added.as_slice(), removed.as_slice()) {
+            diff.bumped.push(
+                VersionBump {
+                    name: name.clone(),
+                    from: from.raw.clone(),
+                    to: to.raw.clone(),
+                    is_downgrade: to < from,
+                }
+            );
+            continue;
+        }
+
+        diff.added.extend(added.into_iter().map(|version| (name.clone(), version.raw.clone())));
+        diff.removed.extend(removed.into_iter().map(|version| (name.clone(), version.raw.clone())));
+    }
+
+    diff
+}
+
+/// Keeps only the packages of the plan whose destination reference does not exist yet,
+/// so that the existing replicas are left untouched.
+pub fn pending_replication_plan(replication_plan: &ReplicationPlan) -> ReplicationPlan {
+    let sh: LocalProjectManipulator = LocalProjectManipulator::new(PathBuf::from("."), false);
+
+    let packages: Vec<Package> = replication_plan.packages
+        .iter()
+        .filter(|package| !destination_exists(&sh, replication_plan, package))
+        .cloned()
+        .collect();
+
+    let mut pending_plan: ReplicationPlan = ReplicationPlan::new(
+        replication_plan.project.clone(),
+        replication_plan.hooks.clone(),
+        packages,
+        replication_plan.config.clone(),
+    );
+
+    // The pending packages have to be replicated under the names they were planned with.
+    pending_plan.names = replication_plan.names.clone();
+
+    pending_plan
+}
+
+fn destination_exists(sh: &LocalProjectManipulator, replication_plan: &ReplicationPlan, package: &Package) -> bool {
+    let Some((destination, reference)) = remote_for(replication_plan, package) else {
+        return false;
+    };
+
+    sh.run_shell(
+        format!("git ls-remote --exit-code --heads {} {}", destination, reference)
+    ).is_ok()
+}
+
+/// The versions of every module of a plan, a module being planned once per target and possibly in several versions.
+fn module_versions(replication_plan: &ReplicationPlan) -> BTreeMap<String, BTreeSet<SemanticVersion>> {
+    let mut module_versions: BTreeMap<String, BTreeSet<SemanticVersion>> = BTreeMap::new();
+
+    for package in &replication_plan.packages {
+        module_versions.entry(package.origin.name()).or_default().insert(package.origin.semantic_version());
+    }
+
+    module_versions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all}};
+
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::model::{
+        package_destination::PackageDestination,
+        package_destination_git::PackageDestinationGit,
+        package_destination_go_proxy::PackageDestinationGoProxy,
+        package_origin::PackageOrigin,
+        package_origin_go_cache::PackageOriginGoCache
+    };
+
+    fn go_package(name: &str, version: &str, destination: PackageDestination) -> Package {
+        Package::new(
+            PackageOriginGoCache::new(name.to_string(), version.to_string(), String::new(), name.to_string(), None, None),
+            destination,
+            Vec::new(),
+            true,
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    fn plan(modules: &[(&str, &str)]) -> ReplicationPlan {
+        let packages: Vec<Package> = modules
+            .iter()
+            .map(|(name, version)| {
+                go_package(name, version, PackageDestinationGit::new(String::new(), version.to_string(), None))
+            })
+            .collect();
+
+        ReplicationPlan::new("demo".to_string(), None, packages, None)
+    }
+
+    #[test]
+    fn diffs_module_versions() {
+        let previous: ReplicationPlan = plan(&[
+            ("example.com/bumped", "v1.0.0"),
+            ("example.com/downgraded", "v1.10.0"),
+            ("example.com/removed", "v1.0.0"),
+            ("example.com/kept", "v1.0.0"),
+            ("example.com/kept", "v1.0.0"),
+            ("example.com/forked", "v1.0.0"),
+        ]);
+
+        let next: ReplicationPlan = plan(&[
+            ("example.com/bumped", "v1.1.0"),
+            ("example.com/downgraded", "v1.9.0"),
+            ("example.com/kept", "v1.0.0"),
+            ("example.com/kept", "v1.0.0"),
+            ("example.com/forked", "v1.0.0"),
+            ("example.com/forked", "v2.0.0"),
+            ("example.com/added", "v0.1.0"),
+        ]);
+
+        let diff: PlanDiff = diff_replication_plans(&previous, &next);
+
+        let bumped: Vec<(String, String, String, bool)> = diff.bumped
+            .iter()
+            .map(|bump| (bump.name.clone(), bump.from.clone(), bump.to.clone(), bump.is_downgrade))
+            .collect();
+
+        assert_eq!(
+            diff.added,
+            vec![
+                ("example.com/added".to_string(), "v0.1.0".to_string()),
+                ("example.com/forked".to_string(), "v2.0.0".to_string()),
+            ]
+        );
+        assert_eq!(diff.removed, vec![("example.com/removed".to_string(), "v1.0.0".to_string())]);
+        assert_eq!(
+            bumped,
+            vec![
+                ("example.com/bumped".to_string(), "v1.0.0".to_string(), "v1.1.0".to_string(), false),
+                ("example.com/downgraded".to_string(), "v1.10.0".to_string(), "v1.9.0".to_string(), true),
+            ]
+        );
+
+        assert!(diff_replication_plans(&next, &next).is_empty());
+    }
+
+    #[test]
+    fn keeps_packages_not_replicated_yet() {
+        let repository: PathBuf = temp_dir().join(format!("source-wand-upgrade-{}", Uuid::new_v4()));
+        create_dir_all(&repository).unwrap();
+
+        let sh: LocalProjectManipulator = LocalProjectManipulator::new(repository.clone(), false);
+        sh.run_shell(
+            "git init -q && git -c user.name=test -c user.email=test@example.com commit -q --allow-empty -m init && git branch -M v1.0.0"
+                .to_string()
+        ).unwrap();
+
+        let git: String = repository.to_str().unwrap_or_default().to_string();
+        let replication_plan: ReplicationPlan = ReplicationPlan::new(
+            "demo".to_string(),
+            None,
+            vec![
+                go_package("example.com/replicated", "v1.0.0", PackageDestinationGit::new(git.clone(), "v1.0.0".to_string(), None)),
+                go_package("example.com/bumped", "v1.1.0", PackageDestinationGit::new(git, "v1.1.0".to_string(), None)),
+                go_package(
+                    "example.com/proxied",
+                    "v1.0.0",
+                    PackageDestination::GoProxy(PackageDestinationGoProxy::new("file:///goproxy".to_string())),
+                ),
+            ],
+            None,
+        );
+
+        let pending: Vec<String> = pending_replication_plan(&replication_plan).packages
+            .iter()
+            .map(|package| package.origin.name())
+            .collect();
+
+        remove_dir_all(&repository).ok();
+
+        assert_eq!(pending, vec!["example.com/bumped", "example.com/proxied"]);
+    }
+
+    #[test]
+    fn keeps_the_names_of_the_plan() {
+        let origin: PackageOrigin = PackageOriginGoCache::new(
+            "go-github-apimachinery-pkg-util-yaml".to_string(),
+            "v1.0.0".to_string(),
+            String::new(),
+            "github.com/openshift/apimachinery/pkg/util/yaml".to_string(),
+            None,
+            None,
+        );
+
+        let mut replication_plan: ReplicationPlan = ReplicationPlan::new(
+            "demo".to_string(),
+            None,
+            vec![
+                Package::new(
+                    origin.clone(),
+                    PackageDestination::GoProxy(PackageDestinationGoProxy::new("file:///goproxy".to_string())),
+                    Vec::new(),
+                    true,
+                    Vec::new(),
+                    Vec::new(),
+                ),
+            ],
+            None,
+        );
+        replication_plan.names.insert(
+            "github.com/openshift/apimachinery/pkg/util/yaml".to_string(),
+            "go-github-apimachinery-pkg-uti-1a2b3c4d".to_string(),
+        );
+
+        let pending_plan: ReplicationPlan = pending_replication_plan(&replication_plan);
+
+        assert_eq!(pending_plan.names, replication_plan.names);
+        assert_eq!(pending_plan.sanitized_name(&origin).sanitized, "go-github-apimachinery-pkg-uti-1a2b3c4d");
+    }
+}
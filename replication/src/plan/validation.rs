@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use regex::Regex;
+
+use crate::model::{
+    package::Package,
+    package_destination::PackageDestination,
+    package_origin::PackageOrigin,
+    replication_plan::ReplicationPlan
+};
+
+#[derive(Debug, Clone)]
+pub struct PlanProblem {
+    pub message: String,
+    pub modules: Vec<String>,
+}
+
+impl PlanProblem {
+    pub fn new(message: String, modules: Vec<String>) -> Self {
+        PlanProblem { message, modules }
+    }
+}
+
+/// Lists everything that would make the replication of a plan fail or overwrite itself.
+pub fn validate_replication_plan(replication_plan: &ReplicationPlan) -> Vec<PlanProblem> {
+    let unexpanded_variable: Regex = Regex::new(r"\$\{?[A-Za-z_][A-Za-z0-9_]*\}?").unwrap();
+
+    let mut problems: Vec<PlanProblem> = Vec::new();
+    let mut modules_by_destination: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for package in &replication_plan.packages {
+        let module: String = match &package.target {
+            Some(target) => format!("{}@{} ({})", package.origin.name(), package.origin.version(), target.base()),
+            None => format!("{}@{}", package.origin.name(), package.origin.version()),
+        };
+
+        if package.origin.version().trim().is_empty() && !matches!(package.origin, PackageOrigin::Local(_)) {
+            problems.push(
+                PlanProblem::new(
+                    format!("{} has no version", package.origin.name()),
+                    vec![module.clone()],
+                )
+            );
+        }
+
+        for template in destination_templates(&package.destination) {
+            for variable in unexpanded_variable.find_iter(&template) {
+                problems.push(
+                    PlanProblem::new(
+                        format!("unknown template variable {} in \"{}\"", variable.as_str(), template),
+                        vec![module.clone()],
+                    )
+                );
+            }
+        }
+
+        for reference in destination_references(&package.destination) {
+            if !is_valid_reference_name(&reference) {
+                problems.push(
+                    PlanProblem::new(
+                        format!("\"{}\" is not a valid git reference name", reference),
+                        vec![module.clone()],
+                    )
+                );
+            }
+        }
+
+        if let Some(destination) = destination_key(replication_plan, package) {
+            modules_by_destination.entry(destination).or_default().push(module);
+        }
+    }
+
+    for (destination, modules) in modules_by_destination {
+        if modules.len() > 1 {
+            problems.push(
+                PlanProblem::new(
+                    format!("{} modules are replicated to {}", modules.len(), destination),
+                    modules,
+                )
+            );
+        }
+    }
+
+    problems
+}
+
+fn destination_templates(destination: &PackageDestination) -> Vec<String> {
+    match destination {
+        PackageDestination::Git(destination) => {
+            let mut templates: Vec<String> = vec![destination.git.clone(), destination.reference.clone()];
+            templates.extend(destination.tag.clone());
+            templates
+        },
+        PackageDestination::GoProxy(destination) => vec![destination.goproxy.clone()],
+        PackageDestination::Directory(destination) => {
+            let mut templates: Vec<String> = vec![destination.directory.clone(), destination.reference.clone()];
+            templates.extend(destination.tag.clone());
+            templates
+        },
+        PackageDestination::Bundle(destination) => {
+            let mut templates: Vec<String> = vec![destination.bundle.clone(), destination.reference.clone()];
+            templates.extend(destination.tag.clone());
+            templates
+        },
+    }
+}
+
+fn destination_references(destination: &PackageDestination) -> Vec<String> {
+    match destination {
+        PackageDestination::Git(destination) => {
+            let mut references: Vec<String> = vec![format!("refs/heads/{}", destination.reference)];
+            references.extend(destination.tag.as_ref().map(|tag| format!("refs/tags/{}", tag)));
+            references
+        },
+        PackageDestination::GoProxy(_) => Vec::new(),
+        PackageDestination::Directory(destination) => {
+            let mut references: Vec<String> = vec![format!("refs/heads/{}", destination.reference)];
+            references.extend(destination.tag.as_ref().map(|tag| format!("refs/tags/{}", tag)));
+            references
+        },
+        PackageDestination::Bundle(destination) => {
+            let mut references: Vec<String> = vec![format!("refs/heads/{}", destination.reference)];
+            references.extend(destination.tag.as_ref().map(|tag| format!("refs/tags/{}", tag)));
+            references
+        },
+    }
+}
+
+/// Where a package ends up, two packages with the same key would overwrite each other.
+fn destination_key(replication_plan: &ReplicationPlan, package: &Package) -> Option<String> {
+    match &package.destination {
+        PackageDestination::Git(destination) => {
+            Some(format!("{} ({})", destination.git, destination.reference))
+        },
+        PackageDestination::GoProxy(_) => None,
+        PackageDestination::Directory(destination) => {
+            Some(destination.repository(&replication_plan.sanitized_name(&package.origin)).to_str().unwrap_or_default().to_string())
+        },
+        PackageDestination::Bundle(destination) => {
+            Some(destination.bundle_file(&replication_plan.sanitized_name(&package.origin)).to_str().unwrap_or_default().to_string())
+        },
+    }
+}
+
+/// Follows the rules of `git check-ref-format`.
+fn is_valid_reference_name(reference: &str) -> bool {
+    let forbidden_character: bool = reference.chars().any(|c| {
+        c.is_ascii_control() || [' ', '~', '^', ':', '?', '*', '[', '\\'].contains(&c)
+    });
+
+    let invalid_component: bool = reference.split('/').any(|component| {
+        component.is_empty() || component.starts_with('.') || component.ends_with(".lock")
+    });
+
+    !forbidden_character
+        && !invalid_component
+        && !reference.contains("..")
+        && !reference.contains("@{")
+        && !reference.ends_with('.')
+        && reference != "@"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        package_destination_bundle::PackageDestinationBundle,
+        package_destination_git::PackageDestinationGit,
+        package_origin_go_cache::PackageOriginGoCache
+    };
+
+    use super::*;
+
+    fn package(module: &str, version: &str, destination: PackageDestination) -> Package {
+        Package::new(
+            PackageOriginGoCache::new(
+                module.replace(['/', '.'], "-"),
+                version.to_string(),
+                String::new(),
+                module.to_string(),
+                None,
+                None,
+            ),
+            destination,
+            Vec::new(),
+            true,
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    fn plan(packages: Vec<Package>) -> ReplicationPlan {
+        ReplicationPlan::new("project".to_string(), None, packages, None)
+    }
+
+    fn git(reference: &str, tag: Option<&str>) -> PackageDestination {
+        PackageDestinationGit::new(
+            "git@example.com:mirror/repository.git".to_string(),
+            reference.to_string(),
+            tag.map(|tag| tag.to_string()),
+        )
+    }
+
+    #[test]
+    fn accepts_a_valid_plan() {
+        let problems: Vec<PlanProblem> = validate_replication_plan(
+            &plan(vec![
+                package("github.com/spf13/cobra", "v1.9.1", git("cobra-1.9.1", Some("v1.9.1"))),
+                package("github.com/spf13/pflag", "v1.0.6", git("pflag-1.0.6", None)),
+            ])
+        );
+
+        assert!(problems.is_empty(), "{:?}", problems);
+    }
+
+    #[test]
+    fn reports_modules_replicated_to_the_same_destination() {
+        let problems: Vec<PlanProblem> = validate_replication_plan(
+            &plan(vec![
+                package("github.com/spf13/cobra", "v1.9.1", git("main", None)),
+                package("github.com/spf13/pflag", "v1.0.6", git("main", None)),
+                package("golang.org/x/sys", "v0.30.0", git("sys", None)),
+            ])
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].modules, vec!["github.com/spf13/cobra@v1.9.1", "github.com/spf13/pflag@v1.0.6"]);
+    }
+
+    #[test]
+    fn reports_packages_named_the_same_in_a_bundle() {
+        let bundle: PackageDestination = PackageDestination::Bundle(
+            PackageDestinationBundle::new("/media/usb".to_string(), "main".to_string(), None)
+        );
+
+        // Both names are truncated to go-github-apimachinery-pkg-util-yaml.
+        let problems: Vec<PlanProblem> = validate_replication_plan(
+            &plan(vec![
+                package("github.com/kubernetes/apimachinery/pkg/util/yaml", "v1.0.0", bundle.clone()),
+                package("github.com/openshift/apimachinery/pkg/util/yaml", "v1.0.0", bundle),
+            ])
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0].message.ends_with("/media/usb/go-github-apimachinery-pkg-util-yaml/main.bundle"),
+            "{}",
+            problems[0].message,
+        );
+    }
+
+    #[test]
+    fn reports_unexpanded_variables_empty_versions_and_invalid_references() {
+        let problems: Vec<PlanProblem> = validate_replication_plan(
+            &plan(vec![
+                package("github.com/spf13/cobra", "", git("$VERSON-24.04", None)),
+                package("github.com/spf13/pflag", "v1.0.6", git("pflag..1.0.6", Some("v1.0.6~1"))),
+            ])
+        );
+
+        let messages: Vec<&String> = problems.iter().map(|problem| &problem.message).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "github.com/spf13/cobra has no version",
+                "unknown template variable $VERSON in \"$VERSON-24.04\"",
+                "\"refs/heads/pflag..1.0.6\" is not a valid git reference name",
+                "\"refs/tags/v1.0.6~1\" is not a valid git reference name",
+            ]
+        );
+    }
+
+    #[test]
+    fn follows_the_rules_of_git_check_ref_format() {
+        let cases: Vec<(&str, bool)> = vec![
+            ("refs/heads/main", true),
+            ("refs/heads/1.9.1-24.04", true),
+            ("refs/heads/release/v1.x", true),
+            ("refs/tags/v0.0.0-20250408123456-abcdef123456", true),
+            ("refs/heads/with space", false),
+            ("refs/heads/a..b", false),
+            ("refs/heads/a~1", false),
+            ("refs/heads/a^", false),
+            ("refs/heads/a:b", false),
+            ("refs/heads/a?", false),
+            ("refs/heads/a*", false),
+            ("refs/heads/a[b", false),
+            ("refs/heads/a\\b", false),
+            ("refs/heads/a@{1}", false),
+            ("refs/heads/.hidden", false),
+            ("refs/heads/branch.lock", false),
+            ("refs/heads/trailing.", false),
+            ("refs/heads/double//slash", false),
+            ("refs/heads/", false),
+            ("refs/heads/tab\there", false),
+            ("@", false),
+        ];
+
+        for (reference, is_valid) in cases {
+            assert_eq!(is_valid_reference_name(reference), is_valid, "{}", reference);
+        }
+    }
+}
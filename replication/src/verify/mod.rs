@@ -0,0 +1,2 @@
+pub mod verify_build;
+pub mod status;
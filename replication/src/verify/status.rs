@@ -0,0 +1,208 @@
+use std::{fmt, fs::canonicalize, path::PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use source_wand_common::{
+    identity::sanitized_name::SanitizedName,
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+
+use crate::{
+    model::{
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        replication_plan::ReplicationPlan
+    },
+    plan::{
+        planner::create_scratch_project,
+        transformations::patches::apply_patches::patch_series
+    }
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicaState {
+    /// The destination reference does not exist yet.
+    Missing,
+    /// The destination holds the same source code as the package.
+    UpToDate,
+    /// The destination holds source code that differs from the package.
+    Diverged,
+    /// The destination exists, but its content cannot be compared to the package.
+    Unverified,
+}
+
+impl fmt::Display for ReplicaState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state: &str = match self {
+            ReplicaState::Missing => "missing",
+            ReplicaState::UpToDate => "up to date",
+            ReplicaState::Diverged => "diverged",
+            ReplicaState::Unverified => "unverified",
+        };
+
+        f.pad(state)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageStatus {
+    pub name: String,
+    pub version: String,
+    pub destination: String,
+    pub reference: String,
+    pub state: ReplicaState,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_files: Vec<String>,
+}
+
+/// Compares every package of the plan against what already exists in its destination.
+pub fn replication_status(replication_plan: &ReplicationPlan) -> Result<Vec<PackageStatus>> {
+    let mut statuses: Vec<PackageStatus> = Vec::new();
+
+    for package in &replication_plan.packages {
+        let (name, version, source) = match &package.origin {
+            PackageOrigin::GoCache(origin) => (origin.name.clone(), origin.version.clone(), Some(origin.path.clone())),
+            PackageOrigin::CargoRegistry(origin) => (origin.name.clone(), origin.version.clone(), Some(origin.path.clone())),
+            PackageOrigin::PythonSdist(origin) => (origin.name.clone(), origin.version.clone(), None),
+            PackageOrigin::Maven(origin) => (origin.name(), origin.version.clone(), None),
+            _ => continue,
+        };
+
+        let Some((destination, reference)) = remote_for(replication_plan, package) else {
+            continue;
+        };
+
+        let sandbox: LocalProjectManipulator = create_scratch_project()?;
+        let status: Result<(ReplicaState, Vec<String>)> = compare_replica(
+            &sandbox,
+            &destination,
+            &reference,
+            source.map(PathBuf::from).filter(|source| source.is_dir()),
+            replication_plan.patches_directory(&replication_plan.sanitized_name(&package.origin))?,
+        );
+        sandbox.cleanup();
+
+        let (state, changed_files) = status?;
+
+        statuses.push(
+            PackageStatus {
+                name,
+                version,
+                destination,
+                reference,
+                state,
+                changed_files,
+            }
+        );
+    }
+
+    Ok(statuses)
+}
+
+/// The repository, or bundle, a package is replicated to along with its reference.
+pub fn remote_for(replication_plan: &ReplicationPlan, package: &Package) -> Option<(String, String)> {
+    let name: SanitizedName = replication_plan.sanitized_name(&package.origin);
+
+    match &package.destination {
+        PackageDestination::Git(destination) => {
+            Some((destination.git.clone(), destination.reference.clone()))
+        },
+        PackageDestination::Directory(destination) => {
+            let repository: PathBuf = destination.repository(&name);
+            Some((repository.to_str().unwrap_or_default().to_string(), destination.reference.clone()))
+        },
+        PackageDestination::Bundle(destination) => {
+            let bundle: PathBuf = destination.bundle_file(&name);
+            Some((bundle.to_str().unwrap_or_default().to_string(), destination.reference.clone()))
+        },
+        PackageDestination::GoProxy(_) => None,
+    }
+}
+
+fn compare_replica(
+    sandbox: &LocalProjectManipulator,
+    destination: &str,
+    reference: &str,
+    source: Option<PathBuf>,
+    patches: Option<PathBuf>,
+) -> Result<(ReplicaState, Vec<String>)> {
+    let reference_exists: bool = sandbox.run_shell(
+        format!("git ls-remote --exit-code --heads {} {}", destination, reference)
+    ).is_ok();
+
+    if !reference_exists {
+        return Ok((ReplicaState::Missing, Vec::new()));
+    }
+
+    let Some(source) = source else {
+        return Ok((ReplicaState::Unverified, Vec::new()));
+    };
+
+    sandbox.run_shell("git init -q".to_string())?;
+    sandbox.run_shell(format!("git fetch -q --depth 1 {} refs/heads/{}", destination, reference))?;
+
+    // The patches of the package are applied to the staged source, so that a patched replica is not seen as diverged.
+    let apply_patches: String = match &patches {
+        Some(patches) => patch_series(patches)?
+            .iter()
+            .map(|(patch, options)| {
+                format!("git apply --cached {} \"{}\" && ", options, patches.join(patch).to_str().unwrap_or_default())
+            })
+            .collect(),
+        None => String::new(),
+    };
+
+    // Stage the package source in an index of its own, so that its tree can be compared
+    // to the replica without copying the (read-only) module cache around.
+    let git_directory: PathBuf = canonicalize(&sandbox.project_root)?.join(".git");
+    let source_tree: String = sandbox.run_shell(
+        format!(
+            "export GIT_INDEX_FILE={git}/source-index && \
+             git -C {source} --git-dir={git} --work-tree=. add -A && \
+             {apply_patches}git write-tree",
+            git = git_directory.to_str().unwrap_or_default(),
+            source = source.to_str().unwrap_or_default(),
+            apply_patches = apply_patches,
+        )
+    )?.trim().to_string();
+
+    // Files added on top of the source (sourcecraft.yaml, debian/, ...) are expected,
+    // only the files of the package that were modified or deleted make a replica diverge.
+    let changed_files: Vec<String> = sandbox.run_shell(
+        format!("git diff-tree -r --name-only --diff-filter=DM {} FETCH_HEAD", source_tree)
+    )?
+        .lines()
+        .map(|line| line.to_string())
+        .collect();
+
+    if changed_files.is_empty() {
+        Ok((ReplicaState::UpToDate, changed_files))
+    }
+    else {
+        Ok((ReplicaState::Diverged, changed_files))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_padded_states() {
+        let cases: Vec<(ReplicaState, &str)> = vec![
+            (ReplicaState::Missing, "missing   "),
+            (ReplicaState::UpToDate, "up to date"),
+            (ReplicaState::Diverged, "diverged  "),
+            (ReplicaState::Unverified, "unverified"),
+        ];
+
+        for (state, expected) in cases {
+            assert_eq!(format!("{:10}", state), expected, "{:?}", state);
+        }
+    }
+}
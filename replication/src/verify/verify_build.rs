@@ -0,0 +1,219 @@
+use std::{collections::BTreeSet, fs::canonicalize, path::{Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use colorize::AnsiColor;
+use regex::Regex;
+use source_wand_common::{
+    identity::sanitized_name::SanitizedName,
+    project_manipulator::{
+        local_project_manipulator::LocalProjectManipulator,
+        project_manipulator::ProjectManipulator
+    }
+};
+
+use crate::{
+    model::{
+        package::Package,
+        package_destination::PackageDestination,
+        package_origin::PackageOrigin,
+        replication_plan::ReplicationPlan
+    },
+    plan::{
+        planner::create_scratch_project,
+        transformations::golang::publish_proxy::escape_module_path
+    }
+};
+
+pub struct BuildVerification {
+    pub missing_modules: Vec<String>,
+    pub build_error: Option<String>,
+}
+
+impl BuildVerification {
+    pub fn is_successful(&self) -> bool {
+        self.missing_modules.is_empty() && self.build_error.is_none()
+    }
+}
+
+/// Builds the top-level project of one target of the plan, i.e. `ubuntu@24.04`, from its replicas.
+/// The target may only be omitted when the plan has a single target.
+pub fn verify_build(replication_plan: &ReplicationPlan, target: Option<&String>) -> Result<BuildVerification> {
+    let targets: BTreeSet<String> = replication_plan.packages
+        .iter()
+        .map(|package| replication_plan.target(package).base())
+        .collect();
+
+    let target: String = match target {
+        Some(target) if targets.contains(target) => target.clone(),
+        Some(target) => bail!("The replication plan has no {} target", target),
+        None if targets.len() > 1 => bail!(
+            "The replication plan has several targets, choose one of {} with --target",
+            targets.into_iter().collect::<Vec<String>>().join(", "),
+        ),
+        None => targets.into_iter().next().unwrap_or_default(),
+    };
+
+    let sandbox: LocalProjectManipulator = create_scratch_project()?;
+    let verification: Result<BuildVerification> = verify_build_in(&sandbox, replication_plan, &target);
+    sandbox.cleanup();
+
+    verification
+}
+
+fn verify_build_in(
+    sandbox: &LocalProjectManipulator,
+    replication_plan: &ReplicationPlan,
+    target: &String,
+) -> Result<BuildVerification> {
+    let sandbox_root: PathBuf = canonicalize(&sandbox.project_root)?;
+    let mut missing_modules: BTreeSet<String> = BTreeSet::new();
+    let mut root_directory: Option<PathBuf> = None;
+    let mut replacements: Vec<(String, PathBuf)> = Vec::new();
+
+    for package in &replication_plan.packages {
+        let PackageOrigin::GoCache(origin) = &package.origin else {
+            continue;
+        };
+
+        if replication_plan.target(package).base() != *target {
+            continue;
+        }
+
+        let module: String = format!("{}@{}", origin.upstream, origin.version);
+        let name: SanitizedName = replication_plan.sanitized_name(&package.origin);
+        let directory: PathBuf = sandbox_root.join(&name.sanitized);
+
+        println!(
+            "{} fetching {} from its replica",
+            "[verify]".green(),
+            module.clone().italic(),
+        );
+
+        if !package.is_library {
+            root_directory = Some(directory.clone());
+        }
+
+        if fetch_replica(sandbox, package, &name, &directory).is_err() {
+            missing_modules.insert(module);
+        }
+        else if package.is_library {
+            replacements.push((module, directory));
+        }
+    }
+
+    let Some(root_directory) = root_directory else {
+        bail!("The replication plan does not contain a top-level project to build");
+    };
+
+    if !root_directory.exists() {
+        return Ok(
+            BuildVerification {
+                missing_modules: missing_modules.into_iter().collect(),
+                build_error: None,
+            }
+        );
+    }
+
+    let root: LocalProjectManipulator = LocalProjectManipulator::new(root_directory, false);
+
+    for (module, directory) in replacements {
+        root.run_shell(
+            format!(
+                "go mod edit -replace={}={}",
+                module,
+                directory.to_str().unwrap_or_default(),
+            )
+        )?;
+    }
+
+    println!(
+        "{} building the top-level project without network access",
+        "[verify]".green(),
+    );
+
+    // An empty module cache of its own makes sure that the build only sees the replicas,
+    // and not the modules that happen to be in the module cache of the user.
+    let module_cache: PathBuf = sandbox_root.join("module-cache");
+
+    let build_error: Option<String> = root.run_shell(
+        format!(
+            "GOMODCACHE=\"{}\" GOPROXY=off GOFLAGS=\"-mod=mod -modcacherw\" GOWORK=off go build ./...",
+            module_cache.to_str().unwrap_or_default(),
+        )
+    ).err().map(|e| e.to_string());
+
+    if let Some(build_error) = &build_error {
+        let lookup_disabled: Regex = Regex::new(r"(\S+@\S+): module lookup disabled by GOPROXY=off").unwrap();
+        let package_not_found: Regex = Regex::new(r"cannot find module providing package (\S+)").unwrap();
+
+        for captures in lookup_disabled.captures_iter(build_error).chain(package_not_found.captures_iter(build_error)) {
+            missing_modules.insert(captures[1].trim_end_matches(':').to_string());
+        }
+    }
+
+    Ok(
+        BuildVerification {
+            missing_modules: missing_modules.into_iter().collect(),
+            build_error,
+        }
+    )
+}
+
+fn fetch_replica(sandbox: &LocalProjectManipulator, package: &Package, name: &SanitizedName, directory: &Path) -> Result<()> {
+    let directory: &str = directory.to_str().unwrap_or_default();
+
+    match (&package.origin, &package.destination) {
+        (_, PackageDestination::Git(destination)) => {
+            sandbox.run_shell(
+                format!(
+                    "git clone --depth 1 --branch {} {} {}",
+                    destination.reference,
+                    destination.git,
+                    directory,
+                )
+            )?;
+        },
+        (PackageOrigin::GoCache(origin), PackageDestination::GoProxy(destination)) => {
+            let archive: PathBuf = canonicalize(&destination.goproxy)?
+                .join(escape_module_path(&origin.upstream))
+                .join("@v")
+                .join(format!("{}.zip", origin.version));
+
+            sandbox.run_shell(
+                format!(
+                    "extract=$(mktemp -d) && \
+                     unzip -q {} -d \"$extract\" && \
+                     mv \"$extract/{}@{}\" {} && \
+                     rm -rf \"$extract\"",
+                    archive.to_str().unwrap_or_default(),
+                    origin.upstream,
+                    origin.version,
+                    directory,
+                )
+            )?;
+        },
+        (PackageOrigin::GoCache(_), PackageDestination::Directory(destination)) => {
+            sandbox.run_shell(
+                format!(
+                    "git clone --depth 1 --branch {} file://{} {}",
+                    destination.reference,
+                    destination.repository(name).to_str().unwrap_or_default(),
+                    directory,
+                )
+            )?;
+        },
+        (PackageOrigin::GoCache(_), PackageDestination::Bundle(destination)) => {
+            sandbox.run_shell(
+                format!(
+                    "git clone --branch {} {} {}",
+                    destination.reference,
+                    destination.bundle_file(name).to_str().unwrap_or_default(),
+                    directory,
+                )
+            )?;
+        },
+        _ => bail!("Unsupported replica"),
+    }
+
+    Ok(())
+}